@@ -0,0 +1,45 @@
+//! Implementation of role-based access control.
+use alloc::{string::String, vec::Vec};
+
+use casper_types::bytesrepr::ToBytes;
+
+use crate::{backend::Storage, constants::ROLES_KEY_NAME, detail, Address};
+
+/// Creates a dictionary item key for a (role, account) pair.
+fn make_dictionary_item_key(role: &str, account: Address) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(
+        &mut role
+            .to_bytes()
+            .unwrap_or_else(|error| detail::revert(error)),
+    );
+    preimage.append(
+        &mut account
+            .to_bytes()
+            .unwrap_or_else(|error| detail::revert(error)),
+    );
+
+    let key_bytes = detail::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+/// Writes whether `account` holds `role`.
+pub(crate) fn write_role_to<S: Storage>(
+    storage: &mut S,
+    role: &str,
+    account: Address,
+    granted: bool,
+) {
+    let dictionary_item_key = make_dictionary_item_key(role, account);
+    storage.write_dictionary_value(ROLES_KEY_NAME, &dictionary_item_key, granted)
+}
+
+/// Reads whether `account` holds `role`.
+///
+/// If a given account was never granted the role, then `false` is returned.
+pub(crate) fn read_role_from<S: Storage>(storage: &S, role: &str, account: Address) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(role, account);
+    storage
+        .read_dictionary_value(ROLES_KEY_NAME, &dictionary_item_key)
+        .unwrap_or_default()
+}
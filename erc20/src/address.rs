@@ -1,12 +1,24 @@
 //! Implementation of an `Address` which refers either an account hash, or a contract hash.
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
 use casper_types::{
-    account::AccountHash,
+    account::{self, AccountHash},
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped, ContractPackageHash, Key,
+    contracts, CLType, CLTyped, ContractHash, ContractPackage, ContractPackageHash, Key, PublicKey,
 };
+#[cfg(feature = "json-schema")]
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+#[cfg(feature = "json-schema")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// An enum representing an [`AccountHash`] or a [`ContractPackageHash`].
+///
+/// With the `json-schema` feature, it is serialized as its formatted string and describes itself
+/// with a JSON schema, for use in off-chain clients.
 #[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum Address {
     /// Represents an account hash.
@@ -35,6 +47,43 @@ impl Address {
     }
 }
 
+impl Address {
+    /// Returns the address of the contract package known to hold `contract_hash`.
+    ///
+    /// Returns [`AddressError::UnknownContract`] if `contract_hash` is not a version of
+    /// `contract_package`, stored under `contract_package_hash`.
+    pub fn from_contract_hash(
+        contract_hash: ContractHash,
+        contract_package_hash: ContractPackageHash,
+        contract_package: &ContractPackage,
+    ) -> Result<Self, AddressError> {
+        if contract_package
+            .versions()
+            .values()
+            .any(|version_hash| *version_hash == contract_hash)
+        {
+            Ok(Self::Contract(contract_package_hash))
+        } else {
+            Err(AddressError::UnknownContract)
+        }
+    }
+
+    /// Formats the address as a hex-encoded string of its serialized [`Key`].
+    ///
+    /// Unlike the formatted string returned by `to_string`, this is the encoding used for
+    /// dictionary item keys and runtime arguments.
+    pub fn to_hex(&self) -> Result<String, bytesrepr::Error> {
+        Ok(hex::encode(self.to_bytes()?))
+    }
+
+    /// Parses an address from a hex-encoded string of its serialized [`Key`], as returned by
+    /// [`Address::to_hex`].
+    pub fn from_hex(input: &str) -> Result<Self, AddressError> {
+        let bytes = hex::decode(input).map_err(|_| AddressError::InvalidHex)?;
+        bytesrepr::deserialize(bytes).map_err(AddressError::InvalidBytes)
+    }
+}
+
 impl From<ContractPackageHash> for Address {
     fn from(contract_package_hash: ContractPackageHash) -> Self {
         Self::Contract(contract_package_hash)
@@ -47,6 +96,18 @@ impl From<AccountHash> for Address {
     }
 }
 
+impl From<PublicKey> for Address {
+    fn from(public_key: PublicKey) -> Self {
+        Self::Account(public_key.to_account_hash())
+    }
+}
+
+impl From<&PublicKey> for Address {
+    fn from(public_key: &PublicKey) -> Self {
+        Self::Account(public_key.to_account_hash())
+    }
+}
+
 impl From<Address> for Key {
     fn from(address: Address) -> Self {
         match address {
@@ -56,6 +117,109 @@ impl From<Address> for Key {
     }
 }
 
+/// Formats the address as `account-hash-<hex>` or `contract-package-wasm<hex>`.
+impl Display for Address {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Address::Account(account_hash) => {
+                formatter.write_str(&account_hash.to_formatted_string())
+            }
+            Address::Contract(contract_package_hash) => {
+                formatter.write_str(&contract_package_hash.to_formatted_string())
+            }
+        }
+    }
+}
+
+/// Parses an address formatted as `account-hash-<hex>` or `contract-package-wasm<hex>`.
+impl FromStr for Address {
+    type Err = AddressError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match AccountHash::from_formatted_str(input) {
+            Ok(account_hash) => return Ok(Address::Account(account_hash)),
+            Err(account::FromStrError::InvalidPrefix) => {}
+            Err(error) => return Err(AddressError::InvalidAccountHash(error)),
+        }
+        match ContractPackageHash::from_formatted_str(input) {
+            Ok(contract_package_hash) => Ok(Address::Contract(contract_package_hash)),
+            Err(contracts::FromStrError::InvalidPrefix) => Err(AddressError::InvalidPrefix),
+            Err(error) => Err(AddressError::InvalidContractPackageHash(error)),
+        }
+    }
+}
+
+/// Serializes the address as its formatted string, e.g. `account-hash-<hex>`.
+#[cfg(feature = "json-schema")]
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes the address from its formatted string, e.g. `account-hash-<hex>`.
+#[cfg(feature = "json-schema")]
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let formatted_string = String::deserialize(deserializer)?;
+        formatted_string.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "json-schema")]
+impl JsonSchema for Address {
+    fn schema_name() -> String {
+        String::from("Address")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema_object = gen.subschema_for::<String>().into_object();
+        schema_object.metadata().description = Some(String::from(
+            "Hex-encoded account hash prefixed with `account-hash-`, or hex-encoded contract \
+            package hash prefixed with `contract-package-wasm`.",
+        ));
+        schema_object.into()
+    }
+}
+
+/// Error returned when an [`Address`] cannot be parsed or derived.
+#[derive(Debug)]
+pub enum AddressError {
+    /// The formatted string is neither an account hash nor a contract package hash.
+    InvalidPrefix,
+    /// The formatted string has the prefix of an account hash, but is malformed.
+    InvalidAccountHash(account::FromStrError),
+    /// The formatted string has the prefix of a contract package hash, but is malformed.
+    InvalidContractPackageHash(contracts::FromStrError),
+    /// The input is not a valid hex-encoded string.
+    InvalidHex,
+    /// The decoded bytes are not a serialized account hash or contract package hash.
+    InvalidBytes(bytesrepr::Error),
+    /// The contract hash is not a version of the contract package.
+    UnknownContract,
+}
+
+impl Display for AddressError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            AddressError::InvalidPrefix => formatter.write_str("invalid address prefix"),
+            AddressError::InvalidAccountHash(error) => {
+                write!(formatter, "invalid account hash: {:?}", error)
+            }
+            AddressError::InvalidContractPackageHash(error) => {
+                write!(formatter, "invalid contract package hash: {:?}", error)
+            }
+            AddressError::InvalidHex => formatter.write_str("invalid hex-encoded address"),
+            AddressError::InvalidBytes(error) => {
+                write!(formatter, "invalid serialized address: {:?}", error)
+            }
+            AddressError::UnknownContract => {
+                formatter.write_str("contract hash is not a version of the contract package")
+            }
+        }
+    }
+}
+
 impl CLTyped for Address {
     fn cl_type() -> casper_types::CLType {
         CLType::Key
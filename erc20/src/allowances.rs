@@ -1,26 +1,22 @@
 //! Implementation of allowances.
 use alloc::{string::String, vec::Vec};
 
-use casper_contract::{
-    contract_api::{runtime, storage},
-    unwrap_or_revert::UnwrapOrRevert,
-};
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{bytesrepr::ToBytes, URef, U256};
 
-use crate::{constants::ALLOWANCES_KEY_NAME, detail, Address};
-
-#[inline]
-pub(crate) fn allowances_uref() -> URef {
-    detail::get_uref(ALLOWANCES_KEY_NAME)
-}
+use crate::{backend::Storage, constants::ALLOWANCE_EXPIRIES_KEY_NAME, detail, Address};
 
 /// Creates a dictionary item key for an (owner, spender) pair.
-fn make_dictionary_item_key(owner: Address, spender: Address) -> String {
+pub(crate) fn make_dictionary_item_key(owner: Address, spender: Address) -> String {
     let mut preimage = Vec::new();
-    preimage.append(&mut owner.to_bytes().unwrap_or_revert());
-    preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    for address in &[owner, spender] {
+        let mut bytes = address
+            .to_bytes()
+            .unwrap_or_else(|error| detail::revert(error));
+        preimage.append(&mut bytes);
+    }
 
-    let key_bytes = runtime::blake2b(&preimage);
+    let key_bytes = detail::blake2b(&preimage);
     hex::encode(&key_bytes)
 }
 
@@ -42,3 +38,34 @@ pub(crate) fn read_allowance_from(allowances_uref: URef, owner: Address, spender
         .unwrap_or_revert()
         .unwrap_or_default()
 }
+
+/// Writes an expiry of an allowance for owner and spender.
+///
+/// `None` means the allowance never expires.
+pub(crate) fn write_allowance_expiry_to<S: Storage>(
+    storage: &mut S,
+    owner: Address,
+    spender: Address,
+    expires_at: Option<u64>,
+) {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    storage.write_dictionary_value(
+        ALLOWANCE_EXPIRIES_KEY_NAME,
+        &dictionary_item_key,
+        expires_at,
+    )
+}
+
+/// Reads an expiry of an allowance for owner and spender.
+///
+/// If an allowance has never been given an expiry, then `None` is returned.
+pub(crate) fn read_allowance_expiry_from<S: Storage>(
+    storage: &S,
+    owner: Address,
+    spender: Address,
+) -> Option<u64> {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    storage
+        .read_dictionary_value(ALLOWANCE_EXPIRIES_KEY_NAME, &dictionary_item_key)
+        .unwrap_or_default()
+}
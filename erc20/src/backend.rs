@@ -0,0 +1,201 @@
+//! Storage backends of the token.
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::cell::RefCell;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    contracts::NamedKeys,
+    CLTyped, ContractPackageHash, URef, U256,
+};
+
+use crate::{
+    allowances, balances,
+    constants::{ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, TOTAL_SUPPLY_KEY_NAME},
+    detail,
+    error::Error,
+    total_supply, Address,
+};
+
+/// State of a token, along with the parts of the execution context its rules depend on.
+///
+/// Values are addressed the same way they are laid out on-chain: by the name of the named key
+/// they are stored under, and by an item key within a dictionary.
+pub trait Storage {
+    /// Reads the balance of `owner`, or a 0 if it never held tokens.
+    fn read_balance(&self, owner: Address) -> U256;
+
+    /// Writes the balance of `owner`.
+    fn write_balance(&mut self, owner: Address, amount: U256);
+
+    /// Reads the amount of `owner`'s tokens allowed to be spent by `spender`, or a 0 if there is
+    /// no allowance.
+    fn read_allowance(&self, owner: Address, spender: Address) -> U256;
+
+    /// Writes the amount of `owner`'s tokens allowed to be spent by `spender`.
+    fn write_allowance(&mut self, owner: Address, spender: Address, amount: U256);
+
+    /// Reads the total supply.
+    fn read_total_supply(&self) -> U256;
+
+    /// Writes the total supply.
+    fn write_total_supply(&mut self, total_supply: U256);
+
+    /// Reads the value stored under the named key `name`, or `None` if nothing has been stored
+    /// there yet.
+    fn read_named_value<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T>;
+
+    /// Writes `value` under the named key `name`.
+    fn write_named_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T);
+
+    /// Reads the item stored under `item_key` in the dictionary named `dictionary_name`, or `None`
+    /// if nothing has been stored there yet.
+    fn read_dictionary_value<T: CLTyped + FromBytes>(
+        &self,
+        dictionary_name: &str,
+        item_key: &str,
+    ) -> Option<T>;
+
+    /// Writes `value` under `item_key` in the dictionary named `dictionary_name`.
+    fn write_dictionary_value<T: CLTyped + ToBytes>(
+        &mut self,
+        dictionary_name: &str,
+        item_key: &str,
+        value: T,
+    );
+
+    /// Returns the address of the direct caller.
+    fn caller(&self) -> Result<Address, Error>;
+
+    /// Returns the current block time, in milliseconds.
+    fn block_time(&self) -> u64;
+
+    /// Returns the hash of the contract package the token is installed as.
+    fn contract_package_hash(&self) -> Result<ContractPackageHash, Error>;
+
+    /// Returns the identifier of the token among the tokens hosted by the same contract, or `None`
+    /// if the token is the only one.
+    fn namespace(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Storage of a token installed as a contract, kept under the contract's named keys.
+///
+/// This is the default backend of [`ERC20`](crate::ERC20). A namespaced storage keeps the state of
+/// one of several tokens hosted by the same contract, under named keys prefixed with its token id.
+#[derive(Default)]
+pub struct ContractStorage {
+    namespace: Option<String>,
+    urefs: RefCell<BTreeMap<String, URef>>,
+}
+
+impl ContractStorage {
+    /// Creates a storage of the token identified by `token_id` among the tokens hosted by the
+    /// current contract.
+    pub fn namespaced(token_id: &str) -> Self {
+        ContractStorage {
+            namespace: Some(token_id.to_string()),
+            urefs: RefCell::default(),
+        }
+    }
+
+    /// Creates a storage which looks up the [`URef`]s in `named_keys` before the named keys of the
+    /// current context.
+    ///
+    /// This is used during installation, as the named keys of the contract are not available in
+    /// the context of the installing session.
+    pub(crate) fn with_named_keys(named_keys: &NamedKeys, namespace: Option<&str>) -> Self {
+        let urefs = named_keys
+            .iter()
+            .filter_map(|(name, key)| key.into_uref().map(|uref| (name.clone(), uref)))
+            .collect();
+        ContractStorage {
+            namespace: namespace.map(ToString::to_string),
+            urefs: RefCell::new(urefs),
+        }
+    }
+
+    fn uref(&self, name: &str) -> URef {
+        let name = detail::namespaced_key_name(self.namespace.as_deref(), name);
+        if let Some(uref) = self.urefs.borrow().get(&name) {
+            return *uref;
+        }
+        let uref = detail::get_uref(&name);
+        self.urefs.borrow_mut().insert(name, uref);
+        uref
+    }
+}
+
+impl Storage for ContractStorage {
+    fn read_balance(&self, owner: Address) -> U256 {
+        balances::read_balance_from(self.uref(BALANCES_KEY_NAME), owner)
+    }
+
+    fn write_balance(&mut self, owner: Address, amount: U256) {
+        balances::write_balance_to(self.uref(BALANCES_KEY_NAME), owner, amount)
+    }
+
+    fn read_allowance(&self, owner: Address, spender: Address) -> U256 {
+        allowances::read_allowance_from(self.uref(ALLOWANCES_KEY_NAME), owner, spender)
+    }
+
+    fn write_allowance(&mut self, owner: Address, spender: Address, amount: U256) {
+        allowances::write_allowance_to(self.uref(ALLOWANCES_KEY_NAME), owner, spender, amount)
+    }
+
+    fn read_total_supply(&self) -> U256 {
+        total_supply::read_total_supply_from(self.uref(TOTAL_SUPPLY_KEY_NAME))
+    }
+
+    fn write_total_supply(&mut self, total_supply: U256) {
+        total_supply::write_total_supply_to(self.uref(TOTAL_SUPPLY_KEY_NAME), total_supply)
+    }
+
+    fn read_named_value<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
+        storage::read(self.uref(name)).unwrap_or_revert()
+    }
+
+    fn write_named_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T) {
+        storage::write(self.uref(name), value)
+    }
+
+    fn read_dictionary_value<T: CLTyped + FromBytes>(
+        &self,
+        dictionary_name: &str,
+        item_key: &str,
+    ) -> Option<T> {
+        storage::dictionary_get(self.uref(dictionary_name), item_key).unwrap_or_revert()
+    }
+
+    fn write_dictionary_value<T: CLTyped + ToBytes>(
+        &mut self,
+        dictionary_name: &str,
+        item_key: &str,
+        value: T,
+    ) {
+        storage::dictionary_put(self.uref(dictionary_name), item_key, value)
+    }
+
+    fn caller(&self) -> Result<Address, Error> {
+        detail::get_immediate_caller_address()
+    }
+
+    fn block_time(&self) -> u64 {
+        u64::from(runtime::get_blocktime())
+    }
+
+    fn contract_package_hash(&self) -> Result<ContractPackageHash, Error> {
+        detail::get_current_contract_package_hash()
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+}
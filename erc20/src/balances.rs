@@ -4,12 +4,14 @@ use alloc::string::String;
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{bytesrepr::ToBytes, URef, U256};
 
-use crate::{constants::BALANCES_KEY_NAME, detail, error::Error, Address};
+use crate::{backend::Storage, detail, error::Error, freeze, Address};
 
 /// Creates a dictionary item key for a dictionary item.
 #[inline]
-fn make_dictionary_item_key(owner: Address) -> String {
-    let preimage = owner.to_bytes().unwrap_or_revert();
+pub(crate) fn make_dictionary_item_key(owner: Address) -> String {
+    let preimage = owner
+        .to_bytes()
+        .unwrap_or_else(|error| detail::revert(error));
     // NOTE: As for now dictionary item keys are limited to 64 characters only. Instead of using
     // hashing (which will effectively hash a hash) we'll use base64. Preimage is about 33 bytes for
     // both Address variants, and approximated base64-encoded length will be 4 * (33 / 3) ~ 44
@@ -19,10 +21,6 @@ fn make_dictionary_item_key(owner: Address) -> String {
     base64::encode(&preimage)
 }
 
-pub(crate) fn get_balances_uref() -> URef {
-    detail::get_uref(BALANCES_KEY_NAME)
-}
-
 /// Writes token balance of a specified account into a dictionary.
 pub(crate) fn write_balance_to(balances_uref: URef, address: Address, amount: U256) {
     let dictionary_item_key = make_dictionary_item_key(address);
@@ -43,33 +41,36 @@ pub(crate) fn read_balance_from(balances_uref: URef, address: Address) -> U256 {
 /// Transfer tokens from the `sender` to the `recipient`.
 ///
 /// This function should not be used directly by contract's entrypoint as it does not validate the
-/// sender.
-pub(crate) fn transfer_balance(
-    balances_uref: URef,
+/// sender. Neither the `sender` nor the `recipient` can be frozen.
+pub(crate) fn transfer_balance<S: Storage>(
+    storage: &mut S,
     sender: Address,
     recipient: Address,
     amount: U256,
 ) -> Result<(), Error> {
+    freeze::ensure_not_frozen(storage, sender)?;
+    freeze::ensure_not_frozen(storage, recipient)?;
+
     if sender == recipient || amount.is_zero() {
         return Ok(());
     }
 
     let new_sender_balance = {
-        let sender_balance = read_balance_from(balances_uref, sender);
+        let sender_balance = storage.read_balance(sender);
         sender_balance
             .checked_sub(amount)
             .ok_or(Error::InsufficientBalance)?
     };
 
     let new_recipient_balance = {
-        let recipient_balance = read_balance_from(balances_uref, recipient);
+        let recipient_balance = storage.read_balance(recipient);
         recipient_balance
             .checked_add(amount)
             .ok_or(Error::Overflow)?
     };
 
-    write_balance_to(balances_uref, sender, new_sender_balance);
-    write_balance_to(balances_uref, recipient, new_recipient_balance);
+    storage.write_balance(sender, new_sender_balance);
+    storage.write_balance(recipient, new_recipient_balance);
 
     Ok(())
 }
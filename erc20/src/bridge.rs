@@ -0,0 +1,145 @@
+//! Implementation of bridge requests.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+use crate::{
+    backend::Storage,
+    constants::{
+        BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME, BRIDGE_BACK_REQUESTS_KEY_NAME,
+        BRIDGE_REQUESTS_KEY_NAME,
+    },
+    detail, Address,
+};
+
+/// An outbound request to release tokens on another chain, recorded for relayers.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BridgeBackRequest {
+    /// Address whose tokens were burned.
+    pub sender: Address,
+    /// Amount of burned tokens.
+    pub amount: U256,
+    /// Identifier of the destination chain.
+    pub to_chain_id: u64,
+    /// Receiver on the destination chain, encoded as raw bytes.
+    pub receiver: Bytes,
+}
+
+impl CLTyped for BridgeBackRequest {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for BridgeBackRequest {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.sender.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.to_chain_id.to_bytes()?);
+        result.append(&mut self.receiver.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.sender.serialized_length()
+            + self.amount.serialized_length()
+            + self.to_chain_id.serialized_length()
+            + self.receiver.serialized_length()
+    }
+}
+
+impl FromBytes for BridgeBackRequest {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (sender, remainder) = Address::from_bytes(bytes)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (to_chain_id, remainder) = u64::from_bytes(remainder)?;
+        let (receiver, remainder) = Bytes::from_bytes(remainder)?;
+        let request = BridgeBackRequest {
+            sender,
+            amount,
+            to_chain_id,
+            receiver,
+        };
+        Ok((request, remainder))
+    }
+}
+
+/// Creates a dictionary item key for an inbound request ID.
+fn make_dictionary_item_key(origin_chain_id: u64, tx_hash: &str, log_index: u64) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(
+        &mut origin_chain_id
+            .to_bytes()
+            .unwrap_or_else(|error| detail::revert(error)),
+    );
+    preimage.append(
+        &mut tx_hash
+            .to_bytes()
+            .unwrap_or_else(|error| detail::revert(error)),
+    );
+    preimage.append(
+        &mut log_index
+            .to_bytes()
+            .unwrap_or_else(|error| detail::revert(error)),
+    );
+
+    let key_bytes = detail::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+/// Marks an inbound request as processed.
+pub(crate) fn write_bridge_request_to<S: Storage>(
+    storage: &mut S,
+    origin_chain_id: u64,
+    tx_hash: &str,
+    log_index: u64,
+) {
+    let dictionary_item_key = make_dictionary_item_key(origin_chain_id, tx_hash, log_index);
+    storage.write_dictionary_value(BRIDGE_REQUESTS_KEY_NAME, &dictionary_item_key, true)
+}
+
+/// Reads whether an inbound request was already processed.
+pub(crate) fn read_bridge_request_from<S: Storage>(
+    storage: &S,
+    origin_chain_id: u64,
+    tx_hash: &str,
+    log_index: u64,
+) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(origin_chain_id, tx_hash, log_index);
+    storage
+        .read_dictionary_value(BRIDGE_REQUESTS_KEY_NAME, &dictionary_item_key)
+        .unwrap_or_default()
+}
+
+/// Reads the number of outbound requests recorded so far.
+pub(crate) fn read_bridge_back_requests_count_from<S: Storage>(storage: &S) -> u64 {
+    storage
+        .read_named_value(BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Records an outbound request under the next index, and returns that index.
+pub(crate) fn append_bridge_back_request_to<S: Storage>(
+    storage: &mut S,
+    request: BridgeBackRequest,
+) -> u64 {
+    let index = read_bridge_back_requests_count_from(storage);
+    storage.write_dictionary_value(BRIDGE_BACK_REQUESTS_KEY_NAME, &index.to_string(), request);
+    storage.write_named_value(BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME, index + 1);
+    index
+}
+
+/// Reads an outbound request recorded under `index`.
+pub(crate) fn read_bridge_back_request_from<S: Storage>(
+    storage: &S,
+    index: u64,
+) -> Option<BridgeBackRequest> {
+    storage.read_dictionary_value(BRIDGE_BACK_REQUESTS_KEY_NAME, &index.to_string())
+}
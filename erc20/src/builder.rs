@@ -0,0 +1,294 @@
+//! Implementation of a builder configuring the installation of a token.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_types::{contracts::NamedKeys, EntryPoint, EntryPoints, Key, U256};
+
+use crate::{
+    constants::{
+        ALLOWANCES_KEY_NAME, ALLOWANCE_EXPIRIES_KEY_NAME, BALANCES_KEY_NAME,
+        BALANCE_SNAPSHOTS_KEY_NAME, BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME,
+        BRIDGE_BACK_REQUESTS_KEY_NAME, BRIDGE_REQUESTS_KEY_NAME, CAP_KEY_NAME, CHAIN_NAME_KEY_NAME,
+        DECIMALS_KEY_NAME, DELEGATES_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME,
+        EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME, FLASH_FEE_BASIS_POINTS_KEY_NAME,
+        FLASH_FEE_RECIPIENT_KEY_NAME, FLASH_LOAN_IN_PROGRESS_KEY_NAME, FROZEN_KEY_NAME,
+        METADATA_KEY_NAME, NAME_KEY_NAME, NONCES_KEY_NAME, PAUSED_KEY_NAME, ROLES_KEY_NAME,
+        SHARED_NAMED_KEYS_KEY_NAME, SNAPSHOT_ID_KEY_NAME, SYMBOL_KEY_NAME, TOKEN_IDS_KEY_NAME,
+        TOTAL_SUPPLY_KEY_NAME, TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME, VOTE_CHECKPOINTS_KEY_NAME,
+    },
+    detail, entry_points,
+    error::Error,
+    Address, TokenMetadata, ERC20,
+};
+
+/// Names of the named keys the token keeps its state under, which extra named keys cannot replace.
+const TOKEN_KEY_NAMES: &[&str] = &[
+    NAME_KEY_NAME,
+    SYMBOL_KEY_NAME,
+    DECIMALS_KEY_NAME,
+    BALANCES_KEY_NAME,
+    ALLOWANCES_KEY_NAME,
+    ALLOWANCE_EXPIRIES_KEY_NAME,
+    TOTAL_SUPPLY_KEY_NAME,
+    ROLES_KEY_NAME,
+    BRIDGE_REQUESTS_KEY_NAME,
+    BRIDGE_BACK_REQUESTS_KEY_NAME,
+    BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME,
+    EVENTS_KEY_NAME,
+    EVENTS_COUNT_KEY_NAME,
+    PAUSED_KEY_NAME,
+    NONCES_KEY_NAME,
+    CHAIN_NAME_KEY_NAME,
+    CAP_KEY_NAME,
+    FROZEN_KEY_NAME,
+    SNAPSHOT_ID_KEY_NAME,
+    BALANCE_SNAPSHOTS_KEY_NAME,
+    TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+    DELEGATES_KEY_NAME,
+    VOTE_CHECKPOINTS_KEY_NAME,
+    FLASH_FEE_BASIS_POINTS_KEY_NAME,
+    FLASH_FEE_RECIPIENT_KEY_NAME,
+    FLASH_LOAN_IN_PROGRESS_KEY_NAME,
+    METADATA_KEY_NAME,
+];
+
+/// Builder configuring the installation of an ERC20 token.
+///
+/// Unless configured otherwise, the token is installed as a locked contract with the
+/// [default set](entry_points::default) of entry points, and its hash is stored under
+/// [`ERC20_TOKEN_CONTRACT_KEY_NAME`]. The installer is granted the administrative roles of the
+/// token.
+///
+/// Permits are accepted only once the name of the chain the token is installed on is set with
+/// [`with_chain_name`](ERC20Builder::with_chain_name).
+///
+/// Optional features such as [`pausable`](ERC20Builder::pausable) only add entry points to the
+/// contract. The contract has to export the `#[no_mangle]` function of each of them itself, since
+/// [`erc20_entry_points!`](crate::erc20_entry_points) generates only the functions of the
+/// default and mintable sets, and an entry point without a matching function fails when called.
+pub struct ERC20Builder {
+    pub(crate) name: String,
+    pub(crate) symbol: String,
+    pub(crate) decimals: u8,
+    pub(crate) initial_supply: U256,
+    pub(crate) initial_holders: Vec<(Address, U256)>,
+    pub(crate) chain_name: String,
+    pub(crate) contract_key_name: String,
+    pub(crate) entry_points: EntryPoints,
+    pub(crate) named_keys: NamedKeys,
+    pub(crate) upgradeable: bool,
+    pub(crate) cap: Option<U256>,
+    pub(crate) pausable: bool,
+    pub(crate) roles: bool,
+    pub(crate) token_id: Option<String>,
+    pub(crate) metadata: Option<TokenMetadata>,
+}
+
+impl ERC20Builder {
+    /// Creates a builder of a token with `name`, `symbol` and `decimals`, and no initial supply.
+    pub fn new(name: String, symbol: String, decimals: u8) -> Self {
+        ERC20Builder {
+            name,
+            symbol,
+            decimals,
+            initial_supply: U256::zero(),
+            initial_holders: Vec::new(),
+            chain_name: String::new(),
+            contract_key_name: ERC20_TOKEN_CONTRACT_KEY_NAME.to_string(),
+            entry_points: entry_points::default(),
+            named_keys: NamedKeys::new(),
+            upgradeable: false,
+            cap: None,
+            pausable: false,
+            roles: false,
+            token_id: None,
+            metadata: None,
+        }
+    }
+
+    /// Mints `initial_supply` tokens to the installer.
+    pub fn with_initial_supply(mut self, initial_supply: U256) -> Self {
+        self.initial_supply = initial_supply;
+        self
+    }
+
+    /// Mints `amount` tokens to `holder`, after the initial supply of the installer.
+    pub fn with_initial_holder(mut self, holder: Address, amount: U256) -> Self {
+        self.initial_holders.push((holder, amount));
+        self
+    }
+
+    /// Binds permits of the token to the network named `chain_name`, such as `casper` or
+    /// `casper-test`, so they cannot be replayed on other networks.
+    ///
+    /// Tokens installed without a chain name reject permits.
+    pub fn with_chain_name(mut self, chain_name: &str) -> Self {
+        self.chain_name = chain_name.to_string();
+        self
+    }
+
+    /// Stores the hash of the installed contract in the installer's named keys under
+    /// `contract_key_name`.
+    pub fn with_contract_key_name(mut self, contract_key_name: &str) -> Self {
+        self.contract_key_name = contract_key_name.to_string();
+        self
+    }
+
+    /// Adds `entry_point` to the entry points of the contract, replacing an entry point with the
+    /// same name.
+    pub fn with_entry_point(mut self, entry_point: EntryPoint) -> Self {
+        self.entry_points.add_entry_point(entry_point);
+        self
+    }
+
+    /// Replaces the default set of entry points with `entry_points`.
+    ///
+    /// # Warning
+    ///
+    /// Using a set of entry points other than the default one might lead to problems with
+    /// integrators such as wallets, and exchanges.
+    pub fn with_entry_points(mut self, entry_points: EntryPoints) -> Self {
+        self.entry_points = entry_points;
+        self
+    }
+
+    /// Adds `key` to the named keys of the contract under `name`.
+    pub fn with_named_key(mut self, name: &str, key: Key) -> Self {
+        self.named_keys.insert(name.to_string(), key);
+        self
+    }
+
+    /// Installs the contract as the first version of an upgradeable contract package, as
+    /// described in [`ERC20::install_upgradeable`].
+    pub fn upgradeable(mut self) -> Self {
+        self.upgradeable = true;
+        self
+    }
+
+    /// Caps the total supply of the token at `cap`, and exposes the `cap` and `lower_cap` entry
+    /// points, whose functions the contract has to export.
+    pub fn with_cap(mut self, cap: U256) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Exposes the `pause`, `unpause` and `is_paused` entry points, whose functions the contract
+    /// has to export.
+    pub fn pausable(mut self) -> Self {
+        self.pausable = true;
+        self
+    }
+
+    /// Exposes the `grant_role`, `revoke_role`, `renounce_role` and `has_role` entry points, whose
+    /// functions the contract has to export.
+    pub fn with_roles(mut self) -> Self {
+        self.roles = true;
+        self
+    }
+
+    /// Sets the extended metadata of the token to `metadata`, and exposes the `metadata`,
+    /// `set_metadata` and `rename` entry points, whose functions the contract has to export.
+    pub fn with_metadata(mut self, metadata: TokenMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Installs the token as the first of several tokens hosted by the contract, identified by
+    /// `token_id`, which has to be non-empty and must not contain `_`.
+    ///
+    /// The state of the token is kept under named keys prefixed with `<token_id>_`, the `token_ids`
+    /// named key lists the hosted tokens, and every entry point takes the additional `token_id`
+    /// argument. Further tokens are added with [`ERC20::create_token`].
+    pub fn with_token_id(mut self, token_id: &str) -> Self {
+        self.token_id = Some(token_id.to_string());
+        self
+    }
+
+    /// Returns the total amount of tokens minted at installation.
+    pub fn total_initial_supply(&self) -> Result<U256, Error> {
+        self.initial_holders
+            .iter()
+            .try_fold(self.initial_supply, |total, (_holder, amount)| {
+                total.checked_add(*amount).ok_or(Error::Overflow)
+            })
+    }
+
+    /// Checks that the token can be installed as configured.
+    ///
+    /// Returns [`Error::InvalidConfiguration`] if the name, the symbol, the contract key name or
+    /// the token id is empty, if the token id contains `_`, or if an extra named key would replace
+    /// one holding the state of the token. Returns [`Error::Overflow`] if the initial supply
+    /// overflows, and [`Error::InvalidCap`] if it exceeds the cap.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.name.is_empty() || self.symbol.is_empty() || self.contract_key_name.is_empty() {
+            return Err(Error::InvalidConfiguration);
+        }
+        let namespace = self.token_id.as_deref();
+        if let Some(token_id) = namespace {
+            if !detail::is_valid_token_id(token_id) {
+                return Err(Error::InvalidConfiguration);
+            }
+        }
+        let is_token_key_name = |name: &String| {
+            (namespace.is_some() && name == TOKEN_IDS_KEY_NAME)
+                || (self.upgradeable && name == SHARED_NAMED_KEYS_KEY_NAME)
+                || TOKEN_KEY_NAMES
+                    .iter()
+                    .any(|key_name| *name == detail::namespaced_key_name(namespace, key_name))
+        };
+        if self.named_keys.keys().any(is_token_key_name) {
+            return Err(Error::InvalidConfiguration);
+        }
+        let total_initial_supply = self.total_initial_supply()?;
+        if let Some(cap) = self.cap {
+            if total_initial_supply > cap {
+                return Err(Error::InvalidCap);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the entry points of the contract, including the ones of the optional features.
+    pub(crate) fn build_entry_points(&self) -> EntryPoints {
+        let mut contract_entry_points = self.entry_points.clone();
+        if self.cap.is_some() {
+            contract_entry_points.add_entry_point(entry_points::cap());
+            contract_entry_points.add_entry_point(entry_points::lower_cap());
+        }
+        if self.pausable {
+            contract_entry_points.add_entry_point(entry_points::pause());
+            contract_entry_points.add_entry_point(entry_points::unpause());
+            contract_entry_points.add_entry_point(entry_points::is_paused());
+        }
+        if self.roles {
+            contract_entry_points.add_entry_point(entry_points::grant_role());
+            contract_entry_points.add_entry_point(entry_points::revoke_role());
+            contract_entry_points.add_entry_point(entry_points::renounce_role());
+            contract_entry_points.add_entry_point(entry_points::has_role());
+        }
+        if self.metadata.is_some() {
+            contract_entry_points.add_entry_point(entry_points::metadata());
+            contract_entry_points.add_entry_point(entry_points::set_metadata());
+            contract_entry_points.add_entry_point(entry_points::rename());
+        }
+        if self.token_id.is_some() {
+            let mut namespaced_entry_points = EntryPoints::new();
+            for entry_point in contract_entry_points.take_entry_points() {
+                namespaced_entry_points.add_entry_point(entry_points::namespaced(entry_point));
+            }
+            namespaced_entry_points.add_entry_point(entry_points::token_ids());
+            return namespaced_entry_points;
+        }
+        contract_entry_points
+    }
+
+    /// Validates the configuration, and installs the token.
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install(self) -> Result<ERC20, Error> {
+        self.validate()?;
+        ERC20::install_contract(self)
+    }
+}
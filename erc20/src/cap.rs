@@ -0,0 +1,17 @@
+//! Implementation of a supply cap.
+
+use casper_types::U256;
+
+use crate::{backend::Storage, constants::CAP_KEY_NAME};
+
+/// Reads a supply cap.
+///
+/// `None` means the supply is not capped.
+pub(crate) fn read_cap_from<S: Storage>(storage: &S) -> Option<U256> {
+    storage.read_named_value(CAP_KEY_NAME).unwrap_or_default()
+}
+
+/// Writes a supply cap.
+pub(crate) fn write_cap_to<S: Storage>(storage: &mut S, value: Option<U256>) {
+    storage.write_named_value(CAP_KEY_NAME, value);
+}
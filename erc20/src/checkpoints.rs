@@ -0,0 +1,102 @@
+//! Implementation of checkpoints kept in a dictionary.
+//!
+//! Checkpoints of a dictionary item are `(key, value)` pairs appended in increasing order of
+//! their key, such as a snapshot ID or a block time. Each checkpoint is kept as a separate item
+//! `<item_key>_<index>`, and their number under `<item_key>`, so recording a checkpoint reads and
+//! writes a constant amount of data however many were recorded before, and past values are
+//! looked up with a binary search over the indices.
+use alloc::{format, string::String};
+
+use casper_types::U256;
+
+use crate::backend::Storage;
+
+/// Creates a dictionary item key for the checkpoint under `index`.
+fn make_checkpoint_item_key(item_key: &str, index: u64) -> String {
+    format!("{}_{}", item_key, index)
+}
+
+/// Reads the number of checkpoints recorded for `item_key`.
+pub(crate) fn read_checkpoints_count_from<S: Storage>(
+    storage: &S,
+    dictionary_name: &str,
+    item_key: &str,
+) -> u64 {
+    storage
+        .read_dictionary_value(dictionary_name, item_key)
+        .unwrap_or_default()
+}
+
+/// Reads the checkpoint recorded for `item_key` under `index`.
+pub(crate) fn read_checkpoint_from<S: Storage>(
+    storage: &S,
+    dictionary_name: &str,
+    item_key: &str,
+    index: u64,
+) -> Option<(u64, U256)> {
+    storage.read_dictionary_value(dictionary_name, &make_checkpoint_item_key(item_key, index))
+}
+
+/// Reads the most recent checkpoint recorded for `item_key`.
+pub(crate) fn read_last_checkpoint_from<S: Storage>(
+    storage: &S,
+    dictionary_name: &str,
+    item_key: &str,
+) -> Option<(u64, U256)> {
+    read_checkpoints_count_from(storage, dictionary_name, item_key)
+        .checked_sub(1)
+        .and_then(|index| read_checkpoint_from(storage, dictionary_name, item_key, index))
+}
+
+/// Records `value` under `key` for `item_key`.
+///
+/// `key` has to be at least the key of the most recent checkpoint, whose value is replaced if
+/// both keys are the same.
+pub(crate) fn write_checkpoint_to<S: Storage>(
+    storage: &mut S,
+    dictionary_name: &str,
+    item_key: &str,
+    key: u64,
+    value: U256,
+) {
+    let count = read_checkpoints_count_from(storage, dictionary_name, item_key);
+    let last = count.checked_sub(1).and_then(|last| {
+        read_checkpoint_from(storage, dictionary_name, item_key, last)
+            .map(|(last_key, _)| (last, last_key))
+    });
+    let index = match last {
+        Some((last, last_key)) if last_key == key => last,
+        _ => {
+            storage.write_dictionary_value(dictionary_name, item_key, count + 1);
+            count
+        }
+    };
+    storage.write_dictionary_value(
+        dictionary_name,
+        &make_checkpoint_item_key(item_key, index),
+        (key, value),
+    );
+}
+
+/// Returns the index of the first checkpoint recorded for `item_key` whose key does not satisfy
+/// `predicate`, or the number of checkpoints if all of them do.
+///
+/// `predicate` has to hold for the keys of a prefix of the checkpoints, as with
+/// [`slice::partition_point`].
+pub(crate) fn partition_point_from<S: Storage>(
+    storage: &S,
+    dictionary_name: &str,
+    item_key: &str,
+    predicate: impl Fn(u64) -> bool,
+) -> u64 {
+    let mut low = 0;
+    let mut high = read_checkpoints_count_from(storage, dictionary_name, item_key);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        match read_checkpoint_from(storage, dictionary_name, item_key, middle) {
+            Some((key, _)) if predicate(key) => low = middle + 1,
+            _ => high = middle,
+        }
+    }
+    low
+}
@@ -0,0 +1,80 @@
+//! Helpers for off-chain clients, such as wallets and indexers, which read the state of an
+//! installed token directly from global state.
+//!
+//! Dictionary item keys are derived by the same functions the contract uses, so they cannot drift
+//! apart.
+use alloc::{string::String, vec::Vec};
+
+use casper_types::{CLValue, CLValueError, U256};
+
+pub use crate::constants::{
+    ALLOWANCES_KEY_NAME, ALLOWANCE_EXPIRIES_KEY_NAME, BALANCES_KEY_NAME, DECIMALS_KEY_NAME,
+    ERC20_TOKEN_CONTRACT_KEY_NAME, EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME, METADATA_KEY_NAME,
+    NAME_KEY_NAME, SYMBOL_KEY_NAME, TOKEN_IDS_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+};
+use crate::{allowances, balances, detail, events, Address, Event, TokenMetadata};
+
+/// Returns the name of the named key under which the token identified by `token_id` keeps the
+/// state otherwise kept under `name`, in a contract hosting several tokens.
+pub fn namespaced_key_name(token_id: &str, name: &str) -> String {
+    detail::namespaced_key_name(Some(token_id), name)
+}
+
+/// Returns the item key under which the balance of `owner` is stored in the
+/// [`BALANCES_KEY_NAME`] dictionary.
+pub fn balance_key(owner: Address) -> String {
+    balances::make_dictionary_item_key(owner)
+}
+
+/// Returns the item key under which the amount of `owner`'s tokens allowed to be spent by
+/// `spender` is stored in the [`ALLOWANCES_KEY_NAME`] dictionary, and its expiry in the
+/// [`ALLOWANCE_EXPIRIES_KEY_NAME`] dictionary.
+pub fn allowance_key(owner: Address, spender: Address) -> String {
+    allowances::make_dictionary_item_key(owner, spender)
+}
+
+/// Returns the item key under which the event with `index` is stored in the [`EVENTS_KEY_NAME`]
+/// dictionary.
+pub fn event_key(index: u64) -> String {
+    events::make_dictionary_item_key(index)
+}
+
+/// Decodes a balance, an allowance or the total supply.
+pub fn decode_amount(cl_value: CLValue) -> Result<U256, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes the name or the symbol of the token.
+pub fn decode_string(cl_value: CLValue) -> Result<String, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes the decimals of the token.
+pub fn decode_decimals(cl_value: CLValue) -> Result<u8, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes the block time after which an allowance expires, or `None` if it never expires.
+pub fn decode_allowance_expiry(cl_value: CLValue) -> Result<Option<u64>, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes the extended metadata of the token.
+pub fn decode_metadata(cl_value: CLValue) -> Result<TokenMetadata, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes the number of events recorded so far.
+pub fn decode_events_count(cl_value: CLValue) -> Result<u64, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes the identifiers of the tokens hosted by a contract.
+pub fn decode_token_ids(cl_value: CLValue) -> Result<Vec<String>, CLValueError> {
+    cl_value.into_t()
+}
+
+/// Decodes an event.
+pub fn decode_event(cl_value: CLValue) -> Result<Event, CLValueError> {
+    cl_value.into_t()
+}
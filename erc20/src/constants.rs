@@ -12,8 +12,62 @@ pub const ERC20_TOKEN_CONTRACT_KEY_NAME: &str = "erc20_token_contract";
 pub const BALANCES_KEY_NAME: &str = "balances";
 /// Name of dictionary-key for `allowances`
 pub const ALLOWANCES_KEY_NAME: &str = "allowances";
+/// Name of dictionary-key for `allowance_expiries`
+pub const ALLOWANCE_EXPIRIES_KEY_NAME: &str = "allowance_expiries";
 /// Name of named-key for `total_supply`
 pub const TOTAL_SUPPLY_KEY_NAME: &str = "total_supply";
+/// Name of dictionary-key for `roles`
+pub const ROLES_KEY_NAME: &str = "roles";
+/// Name of dictionary-key for processed inbound `bridge_requests`
+pub const BRIDGE_REQUESTS_KEY_NAME: &str = "bridge_requests";
+/// Name of dictionary-key for outbound `bridge_back_requests`
+pub const BRIDGE_BACK_REQUESTS_KEY_NAME: &str = "bridge_back_requests";
+/// Name of named-key for `bridge_back_requests_count`
+pub const BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME: &str = "bridge_back_requests_count";
+/// Name of dictionary-key for `events`
+pub const EVENTS_KEY_NAME: &str = "events";
+/// Name of named-key for `events_count`
+pub const EVENTS_COUNT_KEY_NAME: &str = "events_count";
+/// Name of named-key for `paused`
+pub const PAUSED_KEY_NAME: &str = "paused";
+/// Name of dictionary-key for permit `nonces`
+pub const NONCES_KEY_NAME: &str = "nonces";
+/// Name of named-key for `chain_name`
+pub const CHAIN_NAME_KEY_NAME: &str = "chain_name";
+/// Name of named-key for `cap`
+pub const CAP_KEY_NAME: &str = "cap";
+/// Name of dictionary-key for `frozen` accounts
+pub const FROZEN_KEY_NAME: &str = "frozen";
+/// Name of named-key for the most recent `snapshot_id`
+pub const SNAPSHOT_ID_KEY_NAME: &str = "snapshot_id";
+/// Name of dictionary-key for `balance_snapshots`
+pub const BALANCE_SNAPSHOTS_KEY_NAME: &str = "balance_snapshots";
+/// Name of dictionary-key for `total_supply_snapshots`
+pub const TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME: &str = "total_supply_snapshots";
+/// Name of dictionary-key for vote `delegates`
+pub const DELEGATES_KEY_NAME: &str = "delegates";
+/// Name of dictionary-key for `vote_checkpoints`
+pub const VOTE_CHECKPOINTS_KEY_NAME: &str = "vote_checkpoints";
+/// Name of named-key for `flash_fee_basis_points`
+pub const FLASH_FEE_BASIS_POINTS_KEY_NAME: &str = "flash_fee_basis_points";
+/// Name of named-key for `flash_fee_recipient`
+pub const FLASH_FEE_RECIPIENT_KEY_NAME: &str = "flash_fee_recipient";
+/// Name of named-key for `flash_loan_in_progress`
+pub const FLASH_LOAN_IN_PROGRESS_KEY_NAME: &str = "flash_loan_in_progress";
+/// Name of named-key for the `token_ids` of the tokens hosted by a contract
+pub const TOKEN_IDS_KEY_NAME: &str = "token_ids";
+/// Name of named-key for the `shared_named_keys` handed over to every version of an upgradeable
+/// contract
+pub const SHARED_NAMED_KEYS_KEY_NAME: &str = "shared_named_keys";
+/// Name of named-key for `metadata`
+pub const METADATA_KEY_NAME: &str = "metadata";
+
+/// Name of the role allowed to grant and revoke roles.
+pub const ADMIN_ROLE: &str = "admin";
+/// Name of the role allowed to mint new tokens.
+pub const MINTER_ROLE: &str = "minter";
+/// Name of the role allowed to pause and unpause the token.
+pub const PAUSER_ROLE: &str = "pauser";
 
 /// Name of `name` entry point.
 pub const NAME_ENTRY_POINT_NAME: &str = "name";
@@ -27,12 +81,102 @@ pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 pub const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 /// Name of `approve` entry point.
 pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
+/// Name of `approve_with_expiry` entry point.
+pub const APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME: &str = "approve_with_expiry";
 /// Name of `allowance` entry point.
 pub const ALLOWANCE_ENTRY_POINT_NAME: &str = "allowance";
 /// Name of `transfer_from` entry point.
 pub const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
 /// Name of `total_supply` entry point.
 pub const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
+/// Name of `increase_allowance` entry point.
+pub const INCREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "increase_allowance";
+/// Name of `decrease_allowance` entry point.
+pub const DECREASE_ALLOWANCE_ENTRY_POINT_NAME: &str = "decrease_allowance";
+/// Name of `grant_role` entry point.
+pub const GRANT_ROLE_ENTRY_POINT_NAME: &str = "grant_role";
+/// Name of `revoke_role` entry point.
+pub const REVOKE_ROLE_ENTRY_POINT_NAME: &str = "revoke_role";
+/// Name of `renounce_role` entry point.
+pub const RENOUNCE_ROLE_ENTRY_POINT_NAME: &str = "renounce_role";
+/// Name of `has_role` entry point.
+pub const HAS_ROLE_ENTRY_POINT_NAME: &str = "has_role";
+/// Name of `mint` entry point.
+pub const MINT_ENTRY_POINT_NAME: &str = "mint";
+/// Name of `burn` entry point.
+pub const BURN_ENTRY_POINT_NAME: &str = "burn";
+/// Name of `bridge_mint` entry point.
+pub const BRIDGE_MINT_ENTRY_POINT_NAME: &str = "bridge_mint";
+/// Name of `request_bridge_back` entry point.
+pub const REQUEST_BRIDGE_BACK_ENTRY_POINT_NAME: &str = "request_bridge_back";
+/// Name of `pause` entry point.
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+/// Name of `unpause` entry point.
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
+/// Name of `is_paused` entry point.
+pub const IS_PAUSED_ENTRY_POINT_NAME: &str = "is_paused";
+/// Name of `permit` entry point.
+pub const PERMIT_ENTRY_POINT_NAME: &str = "permit";
+/// Name of `nonce_of` entry point.
+pub const NONCE_OF_ENTRY_POINT_NAME: &str = "nonce_of";
+/// Name of `cap` entry point.
+pub const CAP_ENTRY_POINT_NAME: &str = "cap";
+/// Name of `lower_cap` entry point.
+pub const LOWER_CAP_ENTRY_POINT_NAME: &str = "lower_cap";
+/// Name of `freeze` entry point.
+pub const FREEZE_ENTRY_POINT_NAME: &str = "freeze";
+/// Name of `unfreeze` entry point.
+pub const UNFREEZE_ENTRY_POINT_NAME: &str = "unfreeze";
+/// Name of `is_frozen` entry point.
+pub const IS_FROZEN_ENTRY_POINT_NAME: &str = "is_frozen";
+/// Name of `snapshot` entry point.
+pub const SNAPSHOT_ENTRY_POINT_NAME: &str = "snapshot";
+/// Name of `balance_of_at` entry point.
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+/// Name of `total_supply_at` entry point.
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+/// Name of `delegate` entry point.
+pub const DELEGATE_ENTRY_POINT_NAME: &str = "delegate";
+/// Name of `delegates` entry point.
+pub const DELEGATES_ENTRY_POINT_NAME: &str = "delegates";
+/// Name of `get_votes` entry point.
+pub const GET_VOTES_ENTRY_POINT_NAME: &str = "get_votes";
+/// Name of `get_past_votes` entry point.
+pub const GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "get_past_votes";
+/// Name of `transfer_and_call` entry point.
+pub const TRANSFER_AND_CALL_ENTRY_POINT_NAME: &str = "transfer_and_call";
+/// Name of `approve_and_call` entry point.
+pub const APPROVE_AND_CALL_ENTRY_POINT_NAME: &str = "approve_and_call";
+/// Name of the entry point called on contracts receiving tokens through `transfer_and_call`.
+pub const ON_TOKEN_RECEIVED_ENTRY_POINT_NAME: &str = "on_token_received";
+/// Name of the entry point called on contracts approved through `approve_and_call`.
+pub const ON_APPROVAL_RECEIVED_ENTRY_POINT_NAME: &str = "on_approval_received";
+/// Name of `batch_transfer` entry point.
+pub const BATCH_TRANSFER_ENTRY_POINT_NAME: &str = "batch_transfer";
+/// Name of `batch_transfer_from` entry point.
+pub const BATCH_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "batch_transfer_from";
+/// Name of `flash_loan` entry point.
+pub const FLASH_LOAN_ENTRY_POINT_NAME: &str = "flash_loan";
+/// Name of `max_flash_loan` entry point.
+pub const MAX_FLASH_LOAN_ENTRY_POINT_NAME: &str = "max_flash_loan";
+/// Name of `flash_fee` entry point.
+pub const FLASH_FEE_ENTRY_POINT_NAME: &str = "flash_fee";
+/// Name of `set_flash_fee` entry point.
+pub const SET_FLASH_FEE_ENTRY_POINT_NAME: &str = "set_flash_fee";
+/// Name of `set_flash_fee_recipient` entry point.
+pub const SET_FLASH_FEE_RECIPIENT_ENTRY_POINT_NAME: &str = "set_flash_fee_recipient";
+/// Name of the entry point called on contracts receiving a flash loan.
+pub const ON_FLASH_LOAN_ENTRY_POINT_NAME: &str = "on_flash_loan";
+/// Name of `token_ids` entry point.
+pub const TOKEN_IDS_ENTRY_POINT_NAME: &str = "token_ids";
+/// Name of `create_token` entry point.
+pub const CREATE_TOKEN_ENTRY_POINT_NAME: &str = "create_token";
+/// Name of `metadata` entry point.
+pub const METADATA_ENTRY_POINT_NAME: &str = "metadata";
+/// Name of `set_metadata` entry point.
+pub const SET_METADATA_ENTRY_POINT_NAME: &str = "set_metadata";
+/// Name of `rename` entry point.
+pub const RENAME_ENTRY_POINT_NAME: &str = "rename";
 
 /// Name of `address` runtime argument.
 pub const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
@@ -52,3 +196,51 @@ pub const SYMBOL_RUNTIME_ARG_NAME: &str = "symbol";
 pub const DECIMALS_RUNTIME_ARG_NAME: &str = "decimals";
 /// Name of `total_supply` runtime argument.
 pub const TOTAL_SUPPLY_RUNTIME_ARG_NAME: &str = "total_supply";
+/// Name of `role` runtime argument.
+pub const ROLE_RUNTIME_ARG_NAME: &str = "role";
+/// Name of `account` runtime argument.
+pub const ACCOUNT_RUNTIME_ARG_NAME: &str = "account";
+/// Name of `origin_chain_id` runtime argument.
+pub const ORIGIN_CHAIN_ID_RUNTIME_ARG_NAME: &str = "origin_chain_id";
+/// Name of `tx_hash` runtime argument.
+pub const TX_HASH_RUNTIME_ARG_NAME: &str = "tx_hash";
+/// Name of `log_index` runtime argument.
+pub const LOG_INDEX_RUNTIME_ARG_NAME: &str = "log_index";
+/// Name of `to_chain_id` runtime argument.
+pub const TO_CHAIN_ID_RUNTIME_ARG_NAME: &str = "to_chain_id";
+/// Name of `receiver` runtime argument.
+pub const RECEIVER_RUNTIME_ARG_NAME: &str = "receiver";
+/// Name of `public_key` runtime argument.
+pub const PUBLIC_KEY_RUNTIME_ARG_NAME: &str = "public_key";
+/// Name of `deadline` runtime argument.
+pub const DEADLINE_RUNTIME_ARG_NAME: &str = "deadline";
+/// Name of `signature` runtime argument.
+pub const SIGNATURE_RUNTIME_ARG_NAME: &str = "signature";
+/// Name of `cap` runtime argument.
+pub const CAP_RUNTIME_ARG_NAME: &str = "cap";
+/// Name of `snapshot_id` runtime argument.
+pub const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+/// Name of `delegatee` runtime argument.
+pub const DELEGATEE_RUNTIME_ARG_NAME: &str = "delegatee";
+/// Name of `block_time` runtime argument.
+pub const BLOCK_TIME_RUNTIME_ARG_NAME: &str = "block_time";
+/// Name of `data` runtime argument.
+pub const DATA_RUNTIME_ARG_NAME: &str = "data";
+/// Name of `sender` runtime argument.
+pub const SENDER_RUNTIME_ARG_NAME: &str = "sender";
+/// Name of `recipients` runtime argument.
+pub const RECIPIENTS_RUNTIME_ARG_NAME: &str = "recipients";
+/// Name of `amounts` runtime argument.
+pub const AMOUNTS_RUNTIME_ARG_NAME: &str = "amounts";
+/// Name of `initiator` runtime argument.
+pub const INITIATOR_RUNTIME_ARG_NAME: &str = "initiator";
+/// Name of `fee` runtime argument.
+pub const FEE_RUNTIME_ARG_NAME: &str = "fee";
+/// Name of `basis_points` runtime argument.
+pub const BASIS_POINTS_RUNTIME_ARG_NAME: &str = "basis_points";
+/// Name of `expires_at` runtime argument.
+pub const EXPIRES_AT_RUNTIME_ARG_NAME: &str = "expires_at";
+/// Name of `token_id` runtime argument.
+pub const TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
+/// Name of `metadata` runtime argument.
+pub const METADATA_RUNTIME_ARG_NAME: &str = "metadata";
@@ -1,11 +1,18 @@
 //! Implementation details.
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 use core::convert::TryInto;
 
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, URef};
+use casper_types::{
+    bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, ContractPackageHash, URef,
+    BLAKE2B_DIGEST_LENGTH,
+};
 
 use crate::{error::Error, Address};
 
@@ -27,6 +34,80 @@ where
     value
 }
 
+/// Reverts the execution with `error`.
+///
+/// Off-chain, where there is no host to revert to, it panics instead.
+pub(crate) fn revert<T: Into<ApiError>>(error: T) -> ! {
+    #[cfg(target_arch = "wasm32")]
+    runtime::revert(error);
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let error: ApiError = error.into();
+        panic!("reverted with {:?}", error)
+    }
+}
+
+/// Computes a BLAKE2b hash of `data`.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn blake2b(data: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    runtime::blake2b(data)
+}
+
+/// Computes a BLAKE2b hash of `data`.
+///
+/// Off-chain, where there is no host to compute it, the hash is computed natively.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn blake2b(data: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    use blake2::{
+        digest::{Update, VariableOutput},
+        VarBlake2b,
+    };
+
+    let mut hash = [0; BLAKE2B_DIGEST_LENGTH];
+    let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).expect("should create hasher");
+    hasher.update(data);
+    hasher.finalize_variable(|result| hash.copy_from_slice(result));
+    hash
+}
+
+/// Returns the name of the named-key under which the contract package hash is stored.
+pub(crate) fn package_hash_key_name(contract_key_name: &str) -> String {
+    format!("{}_package_hash", contract_key_name)
+}
+
+/// Returns the name of the named-key under which the contract package access token is stored.
+pub(crate) fn access_token_key_name(contract_key_name: &str) -> String {
+    format!("{}_access_token", contract_key_name)
+}
+
+/// Returns the name of the named-key under which the active contract version is stored.
+pub(crate) fn version_key_name(contract_key_name: &str) -> String {
+    format!("{}_version", contract_key_name)
+}
+
+/// Returns the name of the named-key under which the named keys shared by all contract versions
+/// are stored.
+pub(crate) fn named_keys_key_name(contract_key_name: &str) -> String {
+    format!("{}_named_keys", contract_key_name)
+}
+
+/// Returns the name of the named-key under which a token hosted in `namespace` keeps the state
+/// otherwise kept under `name`.
+pub(crate) fn namespaced_key_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{}_{}", namespace, name),
+        None => name.to_string(),
+    }
+}
+
+/// Checks if `token_id` can identify a token hosted alongside others.
+///
+/// A token id has to be non-empty and must not contain `_`, which separates it from the rest of
+/// the names of the token's named keys, so that no two tokens share a named key.
+pub(crate) fn is_valid_token_id(token_id: &str) -> bool {
+    !token_id.is_empty() && !token_id.contains('_')
+}
+
 /// Gets the immediate call stack element of the current execution.
 fn get_immediate_call_stack_item() -> Option<CallStackElement> {
     let call_stack = runtime::get_call_stack();
@@ -75,3 +156,17 @@ pub(crate) fn get_caller_address() -> Result<Address, Error> {
     let address = call_stack_element_to_address(top_of_the_stack);
     Ok(address)
 }
+
+/// Gets the contract package hash of the currently executing contract.
+///
+/// Returns an error if the current execution is not within a stored contract.
+pub(crate) fn get_current_contract_package_hash() -> Result<ContractPackageHash, Error> {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.into_iter().rev().next() {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Ok(contract_package_hash),
+        _ => Err(Error::InvalidContext),
+    }
+}
@@ -2,18 +2,48 @@
 use alloc::{string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Group, Parameter, PublicKey, U256,
 };
 
 use crate::{
     address::Address,
     constants::{
-        ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT_RUNTIME_ARG_NAME,
-        APPROVE_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
-        NAME_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
-        TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
+        ACCOUNT_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, ALLOWANCE_ENTRY_POINT_NAME,
+        AMOUNTS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, APPROVE_AND_CALL_ENTRY_POINT_NAME,
+        APPROVE_ENTRY_POINT_NAME, APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME,
+        BALANCE_OF_AT_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME, BASIS_POINTS_RUNTIME_ARG_NAME,
+        BATCH_TRANSFER_ENTRY_POINT_NAME, BATCH_TRANSFER_FROM_ENTRY_POINT_NAME,
+        BLOCK_TIME_RUNTIME_ARG_NAME, BRIDGE_MINT_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME,
+        CAP_ENTRY_POINT_NAME, CAP_RUNTIME_ARG_NAME, CREATE_TOKEN_ENTRY_POINT_NAME,
+        DATA_RUNTIME_ARG_NAME, DEADLINE_RUNTIME_ARG_NAME, DECIMALS_ENTRY_POINT_NAME,
+        DECIMALS_RUNTIME_ARG_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DELEGATEE_RUNTIME_ARG_NAME,
+        DELEGATES_ENTRY_POINT_NAME, DELEGATE_ENTRY_POINT_NAME, EXPIRES_AT_RUNTIME_ARG_NAME,
+        FEE_RUNTIME_ARG_NAME, FLASH_FEE_ENTRY_POINT_NAME, FLASH_LOAN_ENTRY_POINT_NAME,
+        FREEZE_ENTRY_POINT_NAME, GET_PAST_VOTES_ENTRY_POINT_NAME, GET_VOTES_ENTRY_POINT_NAME,
+        GRANT_ROLE_ENTRY_POINT_NAME, HAS_ROLE_ENTRY_POINT_NAME,
+        INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INITIATOR_RUNTIME_ARG_NAME,
+        IS_FROZEN_ENTRY_POINT_NAME, IS_PAUSED_ENTRY_POINT_NAME, LOG_INDEX_RUNTIME_ARG_NAME,
+        LOWER_CAP_ENTRY_POINT_NAME, MAX_FLASH_LOAN_ENTRY_POINT_NAME, METADATA_ENTRY_POINT_NAME,
+        METADATA_RUNTIME_ARG_NAME, MINT_ENTRY_POINT_NAME, NAME_ENTRY_POINT_NAME,
+        NAME_RUNTIME_ARG_NAME, NONCE_OF_ENTRY_POINT_NAME, ON_APPROVAL_RECEIVED_ENTRY_POINT_NAME,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME, ON_TOKEN_RECEIVED_ENTRY_POINT_NAME,
+        ORIGIN_CHAIN_ID_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, PAUSE_ENTRY_POINT_NAME,
+        PERMIT_ENTRY_POINT_NAME, PUBLIC_KEY_RUNTIME_ARG_NAME, RECEIVER_RUNTIME_ARG_NAME,
+        RECIPIENTS_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME, RENAME_ENTRY_POINT_NAME,
+        RENOUNCE_ROLE_ENTRY_POINT_NAME, REQUEST_BRIDGE_BACK_ENTRY_POINT_NAME,
+        REVOKE_ROLE_ENTRY_POINT_NAME, ROLE_RUNTIME_ARG_NAME, SENDER_RUNTIME_ARG_NAME,
+        SET_FLASH_FEE_ENTRY_POINT_NAME, SET_FLASH_FEE_RECIPIENT_ENTRY_POINT_NAME,
+        SET_METADATA_ENTRY_POINT_NAME, SIGNATURE_RUNTIME_ARG_NAME, SNAPSHOT_ENTRY_POINT_NAME,
+        SNAPSHOT_ID_RUNTIME_ARG_NAME, SPENDER_RUNTIME_ARG_NAME, SYMBOL_ENTRY_POINT_NAME,
+        SYMBOL_RUNTIME_ARG_NAME, TOKEN_IDS_ENTRY_POINT_NAME, TOKEN_ID_RUNTIME_ARG_NAME,
+        TOTAL_SUPPLY_AT_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
+        TOTAL_SUPPLY_RUNTIME_ARG_NAME, TO_CHAIN_ID_RUNTIME_ARG_NAME,
+        TRANSFER_AND_CALL_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
+        TRANSFER_FROM_ENTRY_POINT_NAME, TX_HASH_RUNTIME_ARG_NAME, UNFREEZE_ENTRY_POINT_NAME,
+        UNPAUSE_ENTRY_POINT_NAME,
     },
+    metadata::TokenMetadata,
 };
 
 /// Returns the `name` entry point.
@@ -80,6 +110,49 @@ pub fn approve() -> EntryPoint {
     )
 }
 
+/// Returns the `approve_with_expiry` entry point.
+pub fn approve_with_expiry() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(EXPIRES_AT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `increase_allowance` entry point.
+pub fn increase_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(INCREASE_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `decrease_allowance` entry point.
+pub fn decrease_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DECREASE_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `transfer` entry point.
 pub fn transfer() -> EntryPoint {
     EntryPoint::new(
@@ -127,7 +200,607 @@ pub fn decimals() -> EntryPoint {
     )
 }
 
+/// Returns the `grant_role` entry point.
+pub fn grant_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GRANT_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_role` entry point.
+pub fn revoke_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `renounce_role` entry point.
+pub fn renounce_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RENOUNCE_ROLE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `has_role` entry point.
+pub fn has_role() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HAS_ROLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ROLE_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint` entry point.
+///
+/// The entry point is public, so its function has to check that the caller is allowed to mint, as
+/// the one generated by [`erc20_entry_points!`](crate::erc20_entry_points) with
+/// `mint_burn_authorization` does. Callers can additionally be limited with [`restricted`].
+pub fn mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `burn` entry point.
+///
+/// Like [`mint`], the function of the entry point has to check that the caller is allowed to burn.
+pub fn burn() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns `entry_point` callable only by holders of a [`URef`](casper_types::URef) of one of
+/// `groups` of the contract package.
+///
+/// The groups have to exist in the contract package before a version exposing the entry point is
+/// added. For example, the installer of an
+/// [upgradeable token](crate::ERC20::install_upgradeable) can create them with
+/// `storage::create_contract_user_group`, and expose the restricted entry point in a version added
+/// with [`ERC20::upgrade`](crate::ERC20::upgrade).
+pub fn restricted(entry_point: EntryPoint, groups: Vec<Group>) -> EntryPoint {
+    EntryPoint::new(
+        entry_point.name(),
+        entry_point.args().to_vec(),
+        entry_point.ret().clone(),
+        EntryPointAccess::Groups(groups),
+        entry_point.entry_point_type(),
+    )
+}
+
+/// Returns the `metadata` entry point.
+pub fn metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(METADATA_ENTRY_POINT_NAME),
+        Vec::new(),
+        TokenMetadata::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_metadata` entry point.
+pub fn set_metadata() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_METADATA_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            METADATA_RUNTIME_ARG_NAME,
+            TokenMetadata::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `rename` entry point.
+pub fn rename() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RENAME_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(NAME_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(SYMBOL_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_mint` entry point.
+pub fn bridge_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(ORIGIN_CHAIN_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(TX_HASH_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(LOG_INDEX_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `request_bridge_back` entry point.
+pub fn request_bridge_back() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REQUEST_BRIDGE_BACK_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(TO_CHAIN_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(RECEIVER_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `pause` entry point.
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unpause` entry point.
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_paused` entry point.
+pub fn is_paused() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_PAUSED_ENTRY_POINT_NAME),
+        Vec::new(),
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `permit` entry point.
+pub fn permit() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PERMIT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PUBLIC_KEY_RUNTIME_ARG_NAME, PublicKey::cl_type()),
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DEADLINE_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(SIGNATURE_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `nonce_of` entry point.
+pub fn nonce_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(NONCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cap` entry point.
+pub fn cap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CAP_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `lower_cap` entry point.
+pub fn lower_cap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LOWER_CAP_ENTRY_POINT_NAME),
+        vec![Parameter::new(CAP_RUNTIME_ARG_NAME, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `freeze` entry point.
+pub fn freeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `unfreeze` entry point.
+pub fn unfreeze() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNFREEZE_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_frozen` entry point.
+pub fn is_frozen() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_FROZEN_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `snapshot` entry point.
+pub fn snapshot() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_of_at` entry point.
+pub fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_supply_at` entry point.
+pub fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegate` entry point.
+pub fn delegate() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            DELEGATEE_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `delegates` entry point.
+pub fn delegates() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DELEGATES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_votes` entry point.
+pub fn get_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_VOTES_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_past_votes` entry point.
+pub fn get_past_votes() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(BLOCK_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `transfer_and_call` entry point.
+pub fn transfer_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_and_call` entry point.
+pub fn approve_and_call() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_AND_CALL_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `on_token_received` entry point to be exposed by contracts accepting tokens sent
+/// through `transfer_and_call`.
+pub fn on_token_received() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_TOKEN_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `on_approval_received` entry point to be exposed by contracts accepting approvals
+/// made through `approve_and_call`.
+pub fn on_approval_received() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_APPROVAL_RECEIVED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `batch_transfer` entry point.
+pub fn batch_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `batch_transfer_from` entry point.
+pub fn batch_transfer_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BATCH_TRANSFER_FROM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_loan` entry point.
+pub fn flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECEIVER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_flash_loan` entry point.
+pub fn max_flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_FLASH_LOAN_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_fee` entry point.
+pub fn flash_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_FEE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_flash_fee` entry point.
+pub fn set_flash_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FLASH_FEE_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            BASIS_POINTS_RUNTIME_ARG_NAME,
+            u32::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_flash_fee_recipient` entry point.
+pub fn set_flash_fee_recipient() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FLASH_FEE_RECIPIENT_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            RECIPIENT_RUNTIME_ARG_NAME,
+            Option::<Address>::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `on_flash_loan` entry point to be exposed by contracts receiving flash loans.
+pub fn on_flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ON_FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(INITIATOR_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(FEE_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `token_ids` entry point.
+pub fn token_ids() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOKEN_IDS_ENTRY_POINT_NAME),
+        Vec::new(),
+        Vec::<String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_token` entry point.
+pub fn create_token() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_TOKEN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(NAME_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(SYMBOL_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(DECIMALS_RUNTIME_ARG_NAME, u8::cl_type()),
+            Parameter::new(TOTAL_SUPPLY_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns `entry_point` taking the additional `token_id` argument, which selects the token among
+/// the tokens hosted by the contract.
+pub fn namespaced(entry_point: EntryPoint) -> EntryPoint {
+    let mut args = entry_point.args().to_vec();
+    if !args
+        .iter()
+        .any(|arg| arg.name() == TOKEN_ID_RUNTIME_ARG_NAME)
+    {
+        args.push(Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()));
+    }
+    EntryPoint::new(
+        entry_point.name(),
+        args,
+        entry_point.ret().clone(),
+        entry_point.access().clone(),
+        entry_point.entry_point_type(),
+    )
+}
+
 /// Returns the default set of ERC20 token entry points.
+///
+/// Their functions are generated by [`erc20_entry_points!`](crate::erc20_entry_points), which has
+/// to be kept in sync with this set.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(name());
@@ -139,5 +812,33 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(approve());
     entry_points.add_entry_point(allowance());
     entry_points.add_entry_point(transfer_from());
+    entry_points.add_entry_point(increase_allowance());
+    entry_points.add_entry_point(decrease_allowance());
+    entry_points
+}
+
+/// Returns the default set of ERC20 token entry points, extended with the `mint` and `burn` entry
+/// points.
+///
+/// Their functions are generated by [`erc20_entry_points!`](crate::erc20_entry_points) given a
+/// `mint_burn_authorization`.
+pub fn mintable() -> EntryPoints {
+    let mut entry_points = default();
+    entry_points.add_entry_point(mint());
+    entry_points.add_entry_point(burn());
+    entry_points
+}
+
+/// Returns the default set of entry points of a contract hosting several tokens.
+///
+/// Each entry point of the [default set](default) takes the additional `token_id` argument, and
+/// the `token_ids` entry point lists the hosted tokens. Their functions are generated by
+/// [`erc20_entry_points!(namespaced)`](crate::erc20_entry_points).
+pub fn namespaced_default() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    for entry_point in default().take_entry_points() {
+        entry_points.add_entry_point(namespaced(entry_point));
+    }
+    entry_points.add_entry_point(token_ids());
     entry_points
 }
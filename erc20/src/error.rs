@@ -1,4 +1,9 @@
 //! Error handling on the casper platform.
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
+
 use casper_types::ApiError;
 
 /// Errors which can be returned by the library.
@@ -9,8 +14,14 @@ use casper_types::ApiError;
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
-/// conflicting with the other `Error` variants.
+/// Such a user error has to be in the range `[0..MIN_RESERVED_ERROR)` (i.e. [0, 65471]) to avoid
+/// conflicting with the other `Error` variants, whose codes are the `ERROR_*` constants of this
+/// module. The codes from [`MIN_RESERVED_ERROR`] upwards are reserved for the library, including
+/// those not assigned to a variant yet.
+///
+/// Test suites and SDKs decode the [`ApiError::User`] of a reverted deploy back to an `Error` via
+/// [`Error::try_from`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
     InvalidContext,
@@ -20,14 +31,88 @@ pub enum Error {
     InsufficientAllowance,
     /// Operation would cause an integer overflow.
     Overflow,
+    /// Caller does not hold the role required to perform the operation.
+    Unauthorized,
+    /// Bridge request with the same ID has already been processed.
+    DuplicateBridgeRequest,
+    /// Token movements are halted while the token is paused.
+    Paused,
+    /// Permit deadline has already passed.
+    PermitExpired,
+    /// Permit signature is malformed or was not made by the owner.
+    InvalidSignature,
+    /// Operation would raise the total supply above the cap.
+    CapExceeded,
+    /// New cap is above the current cap, or below the current total supply.
+    InvalidCap,
+    /// Account is frozen and can neither send nor receive tokens.
+    Frozen,
+    /// Snapshot with the given ID has not been taken yet.
+    InvalidSnapshot,
+    /// Block time has not passed yet.
+    InvalidBlockTime,
+    /// Recipient contract rejected the tokens or the approval.
+    CallbackRejected,
+    /// Batch has a different number of recipients and amounts.
+    BatchLengthMismatch,
+    /// Receiver has to be a contract.
+    InvalidReceiver,
+    /// Allowance has expired and can no longer be spent or adjusted.
+    AllowanceExpired,
+    /// Token cannot be installed as configured.
+    InvalidConfiguration,
+    /// Flash loan is above the maximum amount available for a loan.
+    FlashLoanTooLarge,
+    /// Flash loan was requested while another one is in progress.
+    FlashLoanInProgress,
     /// User error.
     User(u16),
 }
 
-const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
-const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
-const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
-const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+/// Code of [`Error::InvalidContext`].
+pub const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
+/// Code of [`Error::InsufficientBalance`].
+pub const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
+/// Code of [`Error::InsufficientAllowance`].
+pub const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
+/// Code of [`Error::Overflow`].
+pub const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+/// Code of [`Error::Unauthorized`].
+pub const ERROR_UNAUTHORIZED: u16 = u16::MAX - 4;
+/// Code of [`Error::DuplicateBridgeRequest`].
+pub const ERROR_DUPLICATE_BRIDGE_REQUEST: u16 = u16::MAX - 5;
+/// Code of [`Error::Paused`].
+pub const ERROR_PAUSED: u16 = u16::MAX - 6;
+/// Code of [`Error::PermitExpired`].
+pub const ERROR_PERMIT_EXPIRED: u16 = u16::MAX - 7;
+/// Code of [`Error::InvalidSignature`].
+pub const ERROR_INVALID_SIGNATURE: u16 = u16::MAX - 8;
+/// Code of [`Error::CapExceeded`].
+pub const ERROR_CAP_EXCEEDED: u16 = u16::MAX - 9;
+/// Code of [`Error::InvalidCap`].
+pub const ERROR_INVALID_CAP: u16 = u16::MAX - 10;
+/// Code of [`Error::Frozen`].
+pub const ERROR_FROZEN: u16 = u16::MAX - 11;
+/// Code of [`Error::InvalidSnapshot`].
+pub const ERROR_INVALID_SNAPSHOT: u16 = u16::MAX - 12;
+/// Code of [`Error::InvalidBlockTime`].
+pub const ERROR_INVALID_BLOCK_TIME: u16 = u16::MAX - 13;
+/// Code of [`Error::CallbackRejected`].
+pub const ERROR_CALLBACK_REJECTED: u16 = u16::MAX - 14;
+/// Code of [`Error::BatchLengthMismatch`].
+pub const ERROR_BATCH_LENGTH_MISMATCH: u16 = u16::MAX - 15;
+/// Code of [`Error::InvalidReceiver`].
+pub const ERROR_INVALID_RECEIVER: u16 = u16::MAX - 16;
+/// Code of [`Error::AllowanceExpired`].
+pub const ERROR_ALLOWANCE_EXPIRED: u16 = u16::MAX - 17;
+/// Code of [`Error::InvalidConfiguration`].
+pub const ERROR_INVALID_CONFIGURATION: u16 = u16::MAX - 18;
+/// Code of [`Error::FlashLoanTooLarge`].
+pub const ERROR_FLASH_LOAN_TOO_LARGE: u16 = u16::MAX - 19;
+/// Code of [`Error::FlashLoanInProgress`].
+pub const ERROR_FLASH_LOAN_IN_PROGRESS: u16 = u16::MAX - 20;
+/// Lowest code reserved for the variants of the library.
+pub const MIN_RESERVED_ERROR: u16 = u16::MAX - 63;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -36,8 +121,112 @@ impl From<Error> for ApiError {
             Error::InsufficientBalance => ERROR_INSUFFICIENT_BALANCE,
             Error::InsufficientAllowance => ERROR_INSUFFICIENT_ALLOWANCE,
             Error::Overflow => ERROR_OVERFLOW,
+            Error::Unauthorized => ERROR_UNAUTHORIZED,
+            Error::DuplicateBridgeRequest => ERROR_DUPLICATE_BRIDGE_REQUEST,
+            Error::Paused => ERROR_PAUSED,
+            Error::PermitExpired => ERROR_PERMIT_EXPIRED,
+            Error::InvalidSignature => ERROR_INVALID_SIGNATURE,
+            Error::CapExceeded => ERROR_CAP_EXCEEDED,
+            Error::InvalidCap => ERROR_INVALID_CAP,
+            Error::Frozen => ERROR_FROZEN,
+            Error::InvalidSnapshot => ERROR_INVALID_SNAPSHOT,
+            Error::InvalidBlockTime => ERROR_INVALID_BLOCK_TIME,
+            Error::CallbackRejected => ERROR_CALLBACK_REJECTED,
+            Error::BatchLengthMismatch => ERROR_BATCH_LENGTH_MISMATCH,
+            Error::InvalidReceiver => ERROR_INVALID_RECEIVER,
+            Error::AllowanceExpired => ERROR_ALLOWANCE_EXPIRED,
+            Error::InvalidConfiguration => ERROR_INVALID_CONFIGURATION,
+            Error::FlashLoanTooLarge => ERROR_FLASH_LOAN_TOO_LARGE,
+            Error::FlashLoanInProgress => ERROR_FLASH_LOAN_IN_PROGRESS,
             Error::User(user_error) => user_error,
         };
         ApiError::User(user_error)
     }
 }
+
+/// Decodes the code of an [`ApiError::User`].
+///
+/// Codes below [`MIN_RESERVED_ERROR`] are decoded as [`Error::User`], while reserved codes which
+/// do not belong to a variant of the library are returned back as the error.
+impl TryFrom<u16> for Error {
+    type Error = u16;
+
+    fn try_from(user_error: u16) -> Result<Self, Self::Error> {
+        let error = match user_error {
+            ERROR_INVALID_CONTEXT => Error::InvalidContext,
+            ERROR_INSUFFICIENT_BALANCE => Error::InsufficientBalance,
+            ERROR_INSUFFICIENT_ALLOWANCE => Error::InsufficientAllowance,
+            ERROR_OVERFLOW => Error::Overflow,
+            ERROR_UNAUTHORIZED => Error::Unauthorized,
+            ERROR_DUPLICATE_BRIDGE_REQUEST => Error::DuplicateBridgeRequest,
+            ERROR_PAUSED => Error::Paused,
+            ERROR_PERMIT_EXPIRED => Error::PermitExpired,
+            ERROR_INVALID_SIGNATURE => Error::InvalidSignature,
+            ERROR_CAP_EXCEEDED => Error::CapExceeded,
+            ERROR_INVALID_CAP => Error::InvalidCap,
+            ERROR_FROZEN => Error::Frozen,
+            ERROR_INVALID_SNAPSHOT => Error::InvalidSnapshot,
+            ERROR_INVALID_BLOCK_TIME => Error::InvalidBlockTime,
+            ERROR_CALLBACK_REJECTED => Error::CallbackRejected,
+            ERROR_BATCH_LENGTH_MISMATCH => Error::BatchLengthMismatch,
+            ERROR_INVALID_RECEIVER => Error::InvalidReceiver,
+            ERROR_ALLOWANCE_EXPIRED => Error::AllowanceExpired,
+            ERROR_INVALID_CONFIGURATION => Error::InvalidConfiguration,
+            ERROR_FLASH_LOAN_TOO_LARGE => Error::FlashLoanTooLarge,
+            ERROR_FLASH_LOAN_IN_PROGRESS => Error::FlashLoanInProgress,
+            user_error if user_error < MIN_RESERVED_ERROR => Error::User(user_error),
+            user_error => return Err(user_error),
+        };
+        Ok(error)
+    }
+}
+
+/// Decodes an [`ApiError::User`] as with `TryFrom<u16>`, or returns an [`ApiError`] which is not
+/// a user error or has an unknown reserved code back as the error.
+impl TryFrom<ApiError> for Error {
+    type Error = ApiError;
+
+    fn try_from(api_error: ApiError) -> Result<Self, Self::Error> {
+        match api_error {
+            ApiError::User(user_error) => Error::try_from(user_error).map_err(ApiError::User),
+            api_error => Err(api_error),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidContext => formatter.write_str("called from within an invalid context"),
+            Error::InsufficientBalance => formatter.write_str("insufficient balance"),
+            Error::InsufficientAllowance => formatter.write_str("insufficient allowance"),
+            Error::Overflow => formatter.write_str("integer overflow"),
+            Error::Unauthorized => formatter.write_str("caller does not hold the required role"),
+            Error::DuplicateBridgeRequest => {
+                formatter.write_str("bridge request already processed")
+            }
+            Error::Paused => formatter.write_str("token is paused"),
+            Error::PermitExpired => formatter.write_str("permit deadline has passed"),
+            Error::InvalidSignature => formatter.write_str("invalid permit signature"),
+            Error::CapExceeded => formatter.write_str("total supply cap exceeded"),
+            Error::InvalidCap => formatter.write_str("invalid total supply cap"),
+            Error::Frozen => formatter.write_str("account is frozen"),
+            Error::InvalidSnapshot => formatter.write_str("snapshot has not been taken yet"),
+            Error::InvalidBlockTime => formatter.write_str("block time has not passed yet"),
+            Error::CallbackRejected => {
+                formatter.write_str("recipient contract rejected the callback")
+            }
+            Error::BatchLengthMismatch => {
+                formatter.write_str("batch recipients and amounts differ in length")
+            }
+            Error::InvalidReceiver => formatter.write_str("receiver is not a contract"),
+            Error::AllowanceExpired => formatter.write_str("allowance has expired"),
+            Error::InvalidConfiguration => formatter.write_str("invalid token configuration"),
+            Error::FlashLoanTooLarge => {
+                formatter.write_str("flash loan exceeds the maximum amount")
+            }
+            Error::FlashLoanInProgress => formatter.write_str("another flash loan is in progress"),
+            Error::User(user_error) => write!(formatter, "user error {}", user_error),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Implementation of an on-chain event log.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+use crate::{
+    backend::Storage,
+    constants::{EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME},
+    Address,
+};
+
+const TRANSFER_TAG: u8 = 0;
+const APPROVAL_TAG: u8 = 1;
+const MINT_TAG: u8 = 2;
+const BURN_TAG: u8 = 3;
+
+/// An event recorded by the token whenever balances or allowances change.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Event {
+    /// Tokens were moved from `sender` to `recipient`.
+    Transfer {
+        /// Address the tokens were taken from.
+        sender: Address,
+        /// Address the tokens were given to.
+        recipient: Address,
+        /// Amount of transferred tokens.
+        amount: U256,
+    },
+    /// `owner` allowed `spender` to transfer up to `amount` of its tokens.
+    Approval {
+        /// Address which owns the tokens.
+        owner: Address,
+        /// Address which is allowed to spend the tokens.
+        spender: Address,
+        /// New allowance.
+        amount: U256,
+    },
+    /// New tokens were created and given to `recipient`.
+    Mint {
+        /// Address the tokens were given to.
+        recipient: Address,
+        /// Amount of minted tokens.
+        amount: U256,
+    },
+    /// Tokens were destroyed and taken from `owner`.
+    Burn {
+        /// Address the tokens were taken from.
+        owner: Address,
+        /// Amount of burned tokens.
+        amount: U256,
+    },
+}
+
+impl CLTyped for Event {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Event {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            Event::Transfer {
+                sender,
+                recipient,
+                amount,
+            } => {
+                result.push(TRANSFER_TAG);
+                result.append(&mut sender.to_bytes()?);
+                result.append(&mut recipient.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+            Event::Approval {
+                owner,
+                spender,
+                amount,
+            } => {
+                result.push(APPROVAL_TAG);
+                result.append(&mut owner.to_bytes()?);
+                result.append(&mut spender.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+            Event::Mint { recipient, amount } => {
+                result.push(MINT_TAG);
+                result.append(&mut recipient.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+            Event::Burn { owner, amount } => {
+                result.push(BURN_TAG);
+                result.append(&mut owner.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        let fields_length = match self {
+            Event::Transfer {
+                sender,
+                recipient,
+                amount,
+            } => {
+                sender.serialized_length()
+                    + recipient.serialized_length()
+                    + amount.serialized_length()
+            }
+            Event::Approval {
+                owner,
+                spender,
+                amount,
+            } => {
+                owner.serialized_length() + spender.serialized_length() + amount.serialized_length()
+            }
+            Event::Mint { recipient, amount } => {
+                recipient.serialized_length() + amount.serialized_length()
+            }
+            Event::Burn { owner, amount } => owner.serialized_length() + amount.serialized_length(),
+        };
+        1 + fields_length
+    }
+}
+
+impl FromBytes for Event {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            TRANSFER_TAG => {
+                let (sender, remainder) = Address::from_bytes(remainder)?;
+                let (recipient, remainder) = Address::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                let event = Event::Transfer {
+                    sender,
+                    recipient,
+                    amount,
+                };
+                Ok((event, remainder))
+            }
+            APPROVAL_TAG => {
+                let (owner, remainder) = Address::from_bytes(remainder)?;
+                let (spender, remainder) = Address::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                let event = Event::Approval {
+                    owner,
+                    spender,
+                    amount,
+                };
+                Ok((event, remainder))
+            }
+            MINT_TAG => {
+                let (recipient, remainder) = Address::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((Event::Mint { recipient, amount }, remainder))
+            }
+            BURN_TAG => {
+                let (owner, remainder) = Address::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((Event::Burn { owner, amount }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// Creates a dictionary item key for an event recorded under `index`.
+pub(crate) fn make_dictionary_item_key(index: u64) -> String {
+    index.to_string()
+}
+
+/// Reads the number of events recorded so far.
+pub(crate) fn read_events_count_from<S: Storage>(storage: &S) -> u64 {
+    storage
+        .read_named_value(EVENTS_COUNT_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Records an event under the next index.
+pub(crate) fn append_event_to<S: Storage>(storage: &mut S, event: Event) {
+    let index = read_events_count_from(storage);
+    storage.write_dictionary_value(EVENTS_KEY_NAME, &make_dictionary_item_key(index), event);
+    storage.write_named_value(EVENTS_COUNT_KEY_NAME, index + 1);
+}
+
+/// Reads an event recorded under `index`.
+pub(crate) fn read_event_from<S: Storage>(storage: &S, index: u64) -> Option<Event> {
+    storage.read_dictionary_value(EVENTS_KEY_NAME, &make_dictionary_item_key(index))
+}
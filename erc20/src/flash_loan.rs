@@ -0,0 +1,62 @@
+//! Implementation of flash loan fees.
+
+use casper_types::U256;
+
+use crate::{
+    backend::Storage,
+    constants::{
+        FLASH_FEE_BASIS_POINTS_KEY_NAME, FLASH_FEE_RECIPIENT_KEY_NAME,
+        FLASH_LOAN_IN_PROGRESS_KEY_NAME,
+    },
+    error::Error,
+    Address,
+};
+
+/// Number of basis points making up the whole loan.
+const BASIS_POINTS_DENOMINATOR: u32 = 10_000;
+
+/// Reads a flash loan fee, in basis points of the loan.
+pub(crate) fn read_flash_fee_basis_points_from<S: Storage>(storage: &S) -> u32 {
+    storage
+        .read_named_value(FLASH_FEE_BASIS_POINTS_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Writes a flash loan fee, in basis points of the loan.
+pub(crate) fn write_flash_fee_basis_points_to<S: Storage>(storage: &mut S, value: u32) {
+    storage.write_named_value(FLASH_FEE_BASIS_POINTS_KEY_NAME, value);
+}
+
+/// Reads a flash loan fee recipient.
+///
+/// `None` means the fees are burned.
+pub(crate) fn read_flash_fee_recipient_from<S: Storage>(storage: &S) -> Option<Address> {
+    storage
+        .read_named_value(FLASH_FEE_RECIPIENT_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Writes a flash loan fee recipient.
+pub(crate) fn write_flash_fee_recipient_to<S: Storage>(storage: &mut S, value: Option<Address>) {
+    storage.write_named_value(FLASH_FEE_RECIPIENT_KEY_NAME, value);
+}
+
+/// Reads whether a flash loan is in progress.
+pub(crate) fn read_flash_loan_in_progress_from<S: Storage>(storage: &S) -> bool {
+    storage
+        .read_named_value(FLASH_LOAN_IN_PROGRESS_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Writes whether a flash loan is in progress.
+pub(crate) fn write_flash_loan_in_progress_to<S: Storage>(storage: &mut S, value: bool) {
+    storage.write_named_value(FLASH_LOAN_IN_PROGRESS_KEY_NAME, value);
+}
+
+/// Computes the fee charged for a flash loan of `amount` tokens.
+pub(crate) fn compute_flash_fee(amount: U256, basis_points: u32) -> Result<U256, Error> {
+    let fee = amount
+        .checked_mul(U256::from(basis_points))
+        .ok_or(Error::Overflow)?;
+    Ok(fee / U256::from(BASIS_POINTS_DENOMINATOR))
+}
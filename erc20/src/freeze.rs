@@ -0,0 +1,28 @@
+//! Implementation of account freezing.
+
+use crate::{backend::Storage, balances, constants::FROZEN_KEY_NAME, error::Error, Address};
+
+/// Writes a freeze flag of a specified account into a dictionary.
+pub(crate) fn write_frozen_to<S: Storage>(storage: &mut S, address: Address, frozen: bool) {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    storage.write_dictionary_value(FROZEN_KEY_NAME, &dictionary_item_key, frozen);
+}
+
+/// Reads a freeze flag of a specified account.
+///
+/// If a given account has never been frozen, then `false` is returned.
+pub(crate) fn read_frozen_from<S: Storage>(storage: &S, address: Address) -> bool {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+
+    storage
+        .read_dictionary_value(FROZEN_KEY_NAME, &dictionary_item_key)
+        .unwrap_or_default()
+}
+
+/// Returns [`Error::Frozen`] if a specified account is frozen.
+pub(crate) fn ensure_not_frozen<S: Storage>(storage: &S, address: Address) -> Result<(), Error> {
+    if read_frozen_from(storage, address) {
+        return Err(Error::Frozen);
+    }
+    Ok(())
+}
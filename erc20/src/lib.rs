@@ -15,87 +15,343 @@
 
 extern crate alloc;
 
+mod access_control;
 mod address;
 mod allowances;
+mod backend;
 mod balances;
+mod bridge;
+mod builder;
+mod cap;
+mod checkpoints;
+#[cfg(feature = "std")]
+pub mod client;
 pub mod constants;
 mod detail;
 pub mod entry_points;
-mod error;
+pub mod error;
+mod events;
+mod flash_loan;
+mod freeze;
+#[doc(hidden)]
+pub mod macros;
+#[cfg(feature = "in-memory")]
+mod memory_storage;
+mod metadata;
+mod pausable;
+mod permit;
+mod receiver;
+mod snapshots;
 mod total_supply;
+mod votes;
 
-use alloc::string::{String, ToString};
-
-use once_cell::unsync::OnceCell;
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{contracts::NamedKeys, EntryPoints, Key, URef, U256};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    contracts::NamedKeys,
+    crypto, ApiError, ContractPackageHash, EntryPoints, Key, PublicKey, Signature, URef, U256,
+};
 
-pub use address::Address;
+pub use address::{Address, AddressError};
+pub use backend::{ContractStorage, Storage};
+pub use bridge::BridgeBackRequest;
+pub use builder::ERC20Builder;
 use constants::{
-    ALLOWANCES_KEY_NAME, BALANCES_KEY_NAME, DECIMALS_KEY_NAME, ERC20_TOKEN_CONTRACT_KEY_NAME,
-    NAME_KEY_NAME, SYMBOL_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+    ADMIN_ROLE, ALLOWANCES_KEY_NAME, ALLOWANCE_EXPIRIES_KEY_NAME, BALANCES_KEY_NAME,
+    BALANCE_SNAPSHOTS_KEY_NAME, BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME, BRIDGE_BACK_REQUESTS_KEY_NAME,
+    BRIDGE_REQUESTS_KEY_NAME, CAP_KEY_NAME, CHAIN_NAME_KEY_NAME, DECIMALS_KEY_NAME,
+    DELEGATES_KEY_NAME, EVENTS_COUNT_KEY_NAME, EVENTS_KEY_NAME, FLASH_FEE_BASIS_POINTS_KEY_NAME,
+    FLASH_FEE_RECIPIENT_KEY_NAME, FLASH_LOAN_IN_PROGRESS_KEY_NAME, FROZEN_KEY_NAME,
+    METADATA_KEY_NAME, MINTER_ROLE, NAME_KEY_NAME, NONCES_KEY_NAME, PAUSED_KEY_NAME, PAUSER_ROLE,
+    ROLES_KEY_NAME, SHARED_NAMED_KEYS_KEY_NAME, SNAPSHOT_ID_KEY_NAME, SYMBOL_KEY_NAME,
+    TOKEN_IDS_KEY_NAME, TOTAL_SUPPLY_KEY_NAME, TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+    VOTE_CHECKPOINTS_KEY_NAME,
 };
 pub use error::Error;
+pub use events::Event;
+#[cfg(feature = "in-memory")]
+pub use memory_storage::MemoryStorage;
+pub use metadata::TokenMetadata;
+pub use permit::{namespaced_permit_message, permit_message};
 
 /// Implementation of ERC20 standard functionality.
-#[derive(Default)]
-pub struct ERC20 {
-    balances_uref: OnceCell<URef>,
-    allowances_uref: OnceCell<URef>,
-    total_supply_uref: OnceCell<URef>,
+///
+/// The state of the token is kept in a [`Storage`], which by default is the [`ContractStorage`] of
+/// the installed contract.
+pub struct ERC20<S = ContractStorage> {
+    storage: S,
 }
 
-impl ERC20 {
-    fn new(balances_uref: URef, allowances_uref: URef, total_supply_uref: URef) -> Self {
-        Self {
-            balances_uref: balances_uref.into(),
-            allowances_uref: allowances_uref.into(),
-            total_supply_uref: total_supply_uref.into(),
-        }
+impl Default for ERC20 {
+    fn default() -> Self {
+        ERC20::new(ContractStorage::default())
     }
+}
 
-    fn total_supply_uref(&self) -> URef {
-        *self
-            .total_supply_uref
-            .get_or_init(total_supply::total_supply_uref)
+impl<S: Storage> ERC20<S> {
+    /// Creates a token backed by `storage`, which is expected to hold an already set up token.
+    pub fn new(storage: S) -> Self {
+        ERC20 { storage }
     }
 
-    fn read_total_supply(&self) -> U256 {
-        total_supply::read_total_supply_from(self.total_supply_uref())
+    /// Returns the storage backing the token.
+    pub fn storage(&self) -> &S {
+        &self.storage
     }
 
-    fn write_total_supply(&self, total_supply: U256) {
-        total_supply::write_total_supply_to(self.total_supply_uref(), total_supply)
+    /// Returns the storage backing the token, e.g. to change the direct caller of an in-memory
+    /// storage.
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
     }
 
-    fn balances_uref(&self) -> URef {
-        *self.balances_uref.get_or_init(balances::get_balances_uref)
+    fn read_total_supply(&self) -> U256 {
+        self.storage.read_total_supply()
     }
 
-    fn read_balance(&self, owner: Address) -> U256 {
-        balances::read_balance_from(self.balances_uref(), owner)
+    fn write_total_supply(&mut self, total_supply: U256) {
+        let snapshot_id = self.read_snapshot_id();
+        let current_total_supply = self.read_total_supply();
+        snapshots::record_total_supply_snapshot_to(
+            &mut self.storage,
+            snapshot_id,
+            current_total_supply,
+        );
+        self.storage.write_total_supply(total_supply)
     }
 
-    fn write_balance(&mut self, owner: Address, amount: U256) {
-        balances::write_balance_to(self.balances_uref(), owner, amount)
+    fn read_balance(&self, owner: Address) -> U256 {
+        self.storage.read_balance(owner)
     }
 
-    fn allowances_uref(&self) -> URef {
-        *self
-            .allowances_uref
-            .get_or_init(allowances::allowances_uref)
+    fn write_balance(&mut self, owner: Address, amount: U256) {
+        self.record_balance_snapshot(owner);
+        self.storage.write_balance(owner, amount)
     }
 
     fn read_allowance(&self, owner: Address, spender: Address) -> U256 {
-        allowances::read_allowance_from(self.allowances_uref(), owner, spender)
+        self.storage.read_allowance(owner, spender)
     }
 
     fn write_allowance(&mut self, owner: Address, spender: Address, amount: U256) {
-        allowances::write_allowance_to(self.allowances_uref(), owner, spender, amount)
+        self.storage.write_allowance(owner, spender, amount)
+    }
+
+    fn read_allowance_expiry(&self, owner: Address, spender: Address) -> Option<u64> {
+        allowances::read_allowance_expiry_from(&self.storage, owner, spender)
+    }
+
+    fn write_allowance_expiry(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        expires_at: Option<u64>,
+    ) {
+        allowances::write_allowance_expiry_to(&mut self.storage, owner, spender, expires_at)
+    }
+
+    fn is_allowance_expired(&self, owner: Address, spender: Address) -> bool {
+        match self.read_allowance_expiry(owner, spender) {
+            Some(expires_at) => self.storage.block_time() > expires_at,
+            None => false,
+        }
+    }
+
+    /// Reads an allowance which can still be spent or adjusted.
+    fn read_unexpired_allowance(&self, owner: Address, spender: Address) -> Result<U256, Error> {
+        if self.is_allowance_expired(owner, spender) {
+            return Err(Error::AllowanceExpired);
+        }
+        Ok(self.read_allowance(owner, spender))
+    }
+
+    fn write_approval(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        amount: U256,
+        expires_at: Option<u64>,
+    ) {
+        self.write_allowance(owner, spender, amount);
+        self.write_allowance_expiry(owner, spender, expires_at);
+        self.emit(Event::Approval {
+            owner,
+            spender,
+            amount,
+        });
+    }
+
+    fn read_role(&self, role: &str, account: Address) -> bool {
+        access_control::read_role_from(&self.storage, role, account)
+    }
+
+    fn write_role(&mut self, role: &str, account: Address, granted: bool) {
+        access_control::write_role_to(&mut self.storage, role, account, granted)
+    }
+
+    fn emit(&mut self, event: Event) {
+        events::append_event_to(&mut self.storage, event)
+    }
+
+    fn read_paused(&self) -> bool {
+        pausable::read_paused_from(&self.storage)
+    }
+
+    fn write_paused(&mut self, paused: bool) {
+        pausable::write_paused_to(&mut self.storage, paused)
+    }
+
+    fn ensure_not_paused(&self) -> Result<(), Error> {
+        if self.read_paused() {
+            return Err(Error::Paused);
+        }
+        Ok(())
+    }
+
+    fn read_nonce(&self, owner: Address) -> u64 {
+        permit::read_nonce_from(&self.storage, owner)
+    }
+
+    fn write_nonce(&mut self, owner: Address, nonce: u64) {
+        permit::write_nonce_to(&mut self.storage, owner, nonce)
+    }
+
+    fn read_cap(&self) -> Option<U256> {
+        cap::read_cap_from(&self.storage)
+    }
+
+    fn write_cap(&mut self, cap: Option<U256>) {
+        cap::write_cap_to(&mut self.storage, cap)
+    }
+
+    fn read_frozen(&self, address: Address) -> bool {
+        freeze::read_frozen_from(&self.storage, address)
+    }
+
+    fn write_frozen(&mut self, address: Address, frozen: bool) {
+        freeze::write_frozen_to(&mut self.storage, address, frozen)
+    }
+
+    fn ensure_not_frozen(&self, address: Address) -> Result<(), Error> {
+        freeze::ensure_not_frozen(&self.storage, address)
+    }
+
+    fn read_snapshot_id(&self) -> u64 {
+        snapshots::read_snapshot_id_from(&self.storage)
+    }
+
+    fn write_snapshot_id(&mut self, snapshot_id: u64) {
+        snapshots::write_snapshot_id_to(&mut self.storage, snapshot_id)
+    }
+
+    /// Records the current balance of `owner` if it is about to change for the first time since
+    /// the most recent snapshot.
+    fn record_balance_snapshot(&mut self, owner: Address) {
+        let snapshot_id = self.read_snapshot_id();
+        let balance = self.read_balance(owner);
+        snapshots::record_balance_snapshot_to(&mut self.storage, snapshot_id, owner, balance)
+    }
+
+    /// Returns [`Error::InvalidSnapshot`] unless a snapshot with `snapshot_id` has been taken.
+    fn ensure_snapshot_taken(&self, snapshot_id: u64) -> Result<(), Error> {
+        if snapshot_id == 0 || snapshot_id > self.read_snapshot_id() {
+            return Err(Error::InvalidSnapshot);
+        }
+        Ok(())
+    }
+
+    fn read_delegate(&self, delegator: Address) -> Option<Address> {
+        votes::read_delegate_from(&self.storage, delegator)
+    }
+
+    fn write_delegate(&mut self, delegator: Address, delegatee: Address) {
+        votes::write_delegate_to(&mut self.storage, delegator, delegatee)
+    }
+
+    /// Moves `amount` of voting power from the `source` delegatee to the `destination` delegatee
+    /// at the current block time.
+    fn move_votes(
+        &mut self,
+        source: Option<Address>,
+        destination: Option<Address>,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let block_time = self.storage.block_time();
+        votes::move_votes(&mut self.storage, source, destination, amount, block_time)
+    }
+
+    /// Moves voting power along with `amount` of tokens moved from the `sender` to the
+    /// `recipient`, where `None` stands for minted or burned tokens.
+    fn move_delegate_votes(
+        &mut self,
+        sender: Option<Address>,
+        recipient: Option<Address>,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let source = sender.and_then(|sender| self.read_delegate(sender));
+        let destination = recipient.and_then(|recipient| self.read_delegate(recipient));
+        self.move_votes(source, destination, amount)
+    }
+
+    fn read_flash_fee_basis_points(&self) -> u32 {
+        flash_loan::read_flash_fee_basis_points_from(&self.storage)
+    }
+
+    fn write_flash_fee_basis_points(&mut self, basis_points: u32) {
+        flash_loan::write_flash_fee_basis_points_to(&mut self.storage, basis_points)
+    }
+
+    fn read_flash_fee_recipient(&self) -> Option<Address> {
+        flash_loan::read_flash_fee_recipient_from(&self.storage)
+    }
+
+    fn write_flash_fee_recipient(&mut self, recipient: Option<Address>) {
+        flash_loan::write_flash_fee_recipient_to(&mut self.storage, recipient)
+    }
+
+    fn read_flash_loan_in_progress(&self) -> bool {
+        flash_loan::read_flash_loan_in_progress_from(&self.storage)
+    }
+
+    fn write_flash_loan_in_progress(&mut self, in_progress: bool) {
+        flash_loan::write_flash_loan_in_progress_to(&mut self.storage, in_progress)
+    }
+
+    /// Returns the sum of `amounts` to be sent to `recipients`.
+    fn batch_total(recipients: &[Address], amounts: &[U256]) -> Result<U256, Error> {
+        if recipients.len() != amounts.len() {
+            return Err(Error::BatchLengthMismatch);
+        }
+        amounts.iter().try_fold(U256::zero(), |total, amount| {
+            total.checked_add(*amount).ok_or(Error::Overflow)
+        })
+    }
+
+    /// Transfers `amounts` of tokens from `sender` to the respective `recipients`.
+    fn batch_transfer_balance(
+        &mut self,
+        sender: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        for (recipient, amount) in recipients.into_iter().zip(amounts) {
+            self.transfer_balance(sender, recipient, amount)?;
+            self.emit(Event::Transfer {
+                sender,
+                recipient,
+                amount,
+            });
+        }
+        Ok(())
     }
 
     fn transfer_balance(
@@ -104,42 +360,59 @@ impl ERC20 {
         recipient: Address,
         amount: U256,
     ) -> Result<(), Error> {
-        balances::transfer_balance(self.balances_uref(), sender, recipient, amount)
-    }
-
-    /// Installs the ERC20 contract with the default set of entry points.
-    ///
-    /// This should be called from within `fn call()` of your contract.
-    pub fn install(
-        name: String,
-        symbol: String,
-        decimals: u8,
-        initial_supply: U256,
-    ) -> Result<ERC20, Error> {
-        let default_entry_points = entry_points::default();
-        ERC20::install_custom(
-            name,
-            symbol,
-            decimals,
-            initial_supply,
-            ERC20_TOKEN_CONTRACT_KEY_NAME,
-            default_entry_points,
-        )
+        self.record_balance_snapshot(sender);
+        self.record_balance_snapshot(recipient);
+        balances::transfer_balance(&mut self.storage, sender, recipient, amount)?;
+        self.move_delegate_votes(Some(sender), Some(recipient), amount)
     }
 
     /// Returns the name of the token.
     pub fn name(&self) -> String {
-        detail::read_from(NAME_KEY_NAME)
+        self.storage
+            .read_named_value(NAME_KEY_NAME)
+            .unwrap_or_default()
     }
 
     /// Returns the symbol of the token.
     pub fn symbol(&self) -> String {
-        detail::read_from(SYMBOL_KEY_NAME)
+        self.storage
+            .read_named_value(SYMBOL_KEY_NAME)
+            .unwrap_or_default()
     }
 
     /// Returns the decimals of the token.
     pub fn decimals(&self) -> u8 {
-        detail::read_from(DECIMALS_KEY_NAME)
+        self.storage
+            .read_named_value(DECIMALS_KEY_NAME)
+            .unwrap_or_default()
+    }
+
+    /// Returns the extended metadata of the token.
+    pub fn metadata(&self) -> TokenMetadata {
+        metadata::read_metadata_from(&self.storage)
+    }
+
+    /// Replaces the extended metadata of the token.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_metadata(&mut self, metadata: TokenMetadata) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        metadata::write_metadata_to(&mut self.storage, metadata);
+        Ok(())
+    }
+
+    /// Changes the name and the symbol of the token, e.g. when the project rebrands.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE). Returns
+    /// [`Error::InvalidConfiguration`] if the name or the symbol is empty.
+    pub fn rename(&mut self, name: String, symbol: String) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        if name.is_empty() || symbol.is_empty() {
+            return Err(Error::InvalidConfiguration);
+        }
+        self.storage.write_named_value(NAME_KEY_NAME, name);
+        self.storage.write_named_value(SYMBOL_KEY_NAME, symbol);
+        Ok(())
     }
 
     /// Returns the total supply of the token.
@@ -154,8 +427,15 @@ impl ERC20 {
 
     /// Transfers `amount` of tokens from the direct caller to `recipient`.
     pub fn transfer(&mut self, recipient: Address, amount: U256) -> Result<(), Error> {
-        let sender = detail::get_immediate_caller_address()?;
-        self.transfer_balance(sender, recipient, amount)
+        self.ensure_not_paused()?;
+        let sender = self.storage.caller()?;
+        self.transfer_balance(sender, recipient, amount)?;
+        self.emit(Event::Transfer {
+            sender,
+            recipient,
+            amount,
+        });
+        Ok(())
     }
 
     /// Transfers `amount` of tokens from `owner` to `recipient` if the direct caller has been
@@ -166,28 +446,183 @@ impl ERC20 {
         recipient: Address,
         amount: U256,
     ) -> Result<(), Error> {
-        let spender = detail::get_immediate_caller_address()?;
+        self.ensure_not_paused()?;
+        let spender = self.storage.caller()?;
+        self.ensure_not_frozen(spender)?;
         if amount.is_zero() {
             return Ok(());
         }
-        let spender_allowance = self.read_allowance(owner, spender);
+        let spender_allowance = self.read_unexpired_allowance(owner, spender)?;
         let new_spender_allowance = spender_allowance
             .checked_sub(amount)
             .ok_or(Error::InsufficientAllowance)?;
         self.transfer_balance(owner, recipient, amount)?;
         self.write_allowance(owner, spender, new_spender_allowance);
+        self.emit(Event::Transfer {
+            sender: owner,
+            recipient,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Transfers `amount` of tokens from the direct caller to `recipient`, and then calls the
+    /// `on_token_received` entry point of `recipient` if it is a contract.
+    ///
+    /// The callback receives the direct caller as `sender`, along with `amount` and `data`. The
+    /// transfer fails with [`Error::CallbackRejected`] unless the callback returns `true`. A
+    /// recipient contract should check that it is called by the token it expects.
+    pub fn transfer_and_call(
+        &mut self,
+        recipient: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let sender = self.storage.caller()?;
+        self.transfer(recipient, amount)?;
+        receiver::notify_token_received(recipient, sender, amount, data)
+    }
+
+    /// Transfers `amounts` of tokens from the direct caller to the respective `recipients`.
+    ///
+    /// The direct caller has to hold the sum of `amounts`. When returned from an entry point, an
+    /// error reverts all the transfers of the batch.
+    pub fn batch_transfer(
+        &mut self,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        let sender = self.storage.caller()?;
+        let total = Self::batch_total(&recipients, &amounts)?;
+        if self.read_balance(sender) < total {
+            return Err(Error::InsufficientBalance);
+        }
+        self.batch_transfer_balance(sender, recipients, amounts)
+    }
+
+    /// Transfers `amounts` of tokens from `owner` to the respective `recipients` if the direct
+    /// caller has been previously approved to spend the sum of `amounts` on behalf of the owner.
+    ///
+    /// When returned from an entry point, an error reverts all the transfers of the batch.
+    pub fn batch_transfer_from(
+        &mut self,
+        owner: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        let spender = self.storage.caller()?;
+        self.ensure_not_frozen(spender)?;
+        let total = Self::batch_total(&recipients, &amounts)?;
+        let new_spender_allowance = self
+            .read_unexpired_allowance(owner, spender)?
+            .checked_sub(total)
+            .ok_or(Error::InsufficientAllowance)?;
+        if self.read_balance(owner) < total {
+            return Err(Error::InsufficientBalance);
+        }
+        self.batch_transfer_balance(owner, recipients, amounts)?;
+        self.write_allowance(owner, spender, new_spender_allowance);
         Ok(())
     }
 
     /// Allows `spender` to transfer up to `amount` of the direct caller's tokens.
+    ///
+    /// The allowance does not expire, even if it replaces one given with
+    /// [`ERC20::approve_with_expiry`].
     pub fn approve(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
-        let owner = detail::get_immediate_caller_address()?;
-        self.write_allowance(owner, spender, amount);
+        self.ensure_not_paused()?;
+        let owner = self.storage.caller()?;
+        self.write_approval(owner, spender, amount, None);
+        Ok(())
+    }
+
+    /// Allows `spender` to transfer up to `amount` of the direct caller's tokens until the block
+    /// time passes `expires_at`.
+    ///
+    /// Once expired, the allowance reads as 0 and spending it fails with
+    /// [`Error::AllowanceExpired`]. Returns [`Error::AllowanceExpired`] if `expires_at` has
+    /// already passed.
+    pub fn approve_with_expiry(
+        &mut self,
+        spender: Address,
+        amount: U256,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        if self.storage.block_time() > expires_at {
+            return Err(Error::AllowanceExpired);
+        }
+        let owner = self.storage.caller()?;
+        self.write_approval(owner, spender, amount, Some(expires_at));
         Ok(())
     }
 
-    /// Returns the amount of `owner`'s tokens allowed to be spent by `spender`.
+    /// Allows `spender` to transfer up to `amount` of the direct caller's tokens, and then calls
+    /// the `on_approval_received` entry point of `spender` if it is a contract.
+    ///
+    /// The callback receives the direct caller as `sender`, along with `amount` and `data`. The
+    /// approval fails with [`Error::CallbackRejected`] unless the callback returns `true`.
+    pub fn approve_and_call(
+        &mut self,
+        spender: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let owner = self.storage.caller()?;
+        self.approve(spender, amount)?;
+        receiver::notify_approval_received(spender, owner, amount, data)
+    }
+
+    /// Increases the amount of the direct caller's tokens which `spender` is allowed to transfer
+    /// by `amount`.
+    ///
+    /// The expiry of the allowance, if any, is kept.
+    pub fn increase_allowance(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        let owner = self.storage.caller()?;
+        let new_allowance = {
+            let allowance = self.read_unexpired_allowance(owner, spender)?;
+            allowance.checked_add(amount).ok_or(Error::Overflow)?
+        };
+        self.write_allowance(owner, spender, new_allowance);
+        self.emit(Event::Approval {
+            owner,
+            spender,
+            amount: new_allowance,
+        });
+        Ok(())
+    }
+
+    /// Decreases the amount of the direct caller's tokens which `spender` is allowed to transfer
+    /// by `amount`.
+    ///
+    /// The expiry of the allowance, if any, is kept.
+    pub fn decrease_allowance(&mut self, spender: Address, amount: U256) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        let owner = self.storage.caller()?;
+        let new_allowance = {
+            let allowance = self.read_unexpired_allowance(owner, spender)?;
+            allowance
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientAllowance)?
+        };
+        self.write_allowance(owner, spender, new_allowance);
+        self.emit(Event::Approval {
+            owner,
+            spender,
+            amount: new_allowance,
+        });
+        Ok(())
+    }
+
+    /// Returns the amount of `owner`'s tokens allowed to be spent by `spender`, or 0 if the
+    /// allowance has expired.
     pub fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        if self.is_allowance_expired(owner, spender) {
+            return U256::zero();
+        }
         self.read_allowance(owner, spender)
     }
 
@@ -198,6 +633,8 @@ impl ERC20 {
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
     /// public entry point.
     pub fn mint(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        self.ensure_not_frozen(owner)?;
         let new_balance = {
             let balance = self.read_balance(owner);
             balance.checked_add(amount).ok_or(Error::Overflow)?
@@ -206,8 +643,18 @@ impl ERC20 {
             let total_supply: U256 = self.read_total_supply();
             total_supply.checked_add(amount).ok_or(Error::Overflow)?
         };
+        if let Some(cap) = self.read_cap() {
+            if new_total_supply > cap {
+                return Err(Error::CapExceeded);
+            }
+        }
         self.write_balance(owner, new_balance);
         self.write_total_supply(new_total_supply);
+        self.move_delegate_votes(None, Some(owner), amount)?;
+        self.emit(Event::Mint {
+            recipient: owner,
+            amount,
+        });
         Ok(())
     }
 
@@ -219,6 +666,7 @@ impl ERC20 {
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
     /// public entry point.
     pub fn burn(&mut self, owner: Address, amount: U256) -> Result<(), Error> {
+        self.ensure_not_paused()?;
         let new_balance = {
             let balance = self.read_balance(owner);
             balance
@@ -231,9 +679,479 @@ impl ERC20 {
         };
         self.write_balance(owner, new_balance);
         self.write_total_supply(new_total_supply);
+        self.move_delegate_votes(Some(owner), None, amount)?;
+        self.emit(Event::Burn { owner, amount });
+        Ok(())
+    }
+
+    /// Returns the maximum amount of tokens available for a flash loan.
+    pub fn max_flash_loan(&self) -> U256 {
+        let max_total_supply = self.read_cap().unwrap_or_else(U256::max_value);
+        max_total_supply.saturating_sub(self.read_total_supply())
+    }
+
+    /// Returns the fee charged for a flash loan of `amount` tokens.
+    pub fn flash_fee(&self, amount: U256) -> Result<U256, Error> {
+        flash_loan::compute_flash_fee(amount, self.read_flash_fee_basis_points())
+    }
+
+    /// Lends `amount` of newly minted tokens to the `receiver` contract for the duration of a call
+    /// to its `on_flash_loan` entry point.
+    ///
+    /// The callback receives the direct caller as `initiator`, along with `amount`, the fee
+    /// returned by [`ERC20::flash_fee`] and `data`, and has to return `true`. Before returning, the
+    /// receiver has to approve this token's contract package to spend `amount` plus the fee, and
+    /// the loan fails with [`Error::AllowanceExpired`] if that approval has expired. The loan is
+    /// then burned, and the fee is either transferred to the
+    /// [flash fee recipient](ERC20::set_flash_fee_recipient) or burned if there is none.
+    ///
+    /// Returns [`Error::FlashLoanTooLarge`] if `amount` is above [`ERC20::max_flash_loan`], and
+    /// [`Error::FlashLoanInProgress`] if the receiver or anyone else asks for another loan before
+    /// the current one is repaid. A loan charging a fee is refused with [`Error::Frozen`] while
+    /// the fee recipient is frozen, before anything is lent.
+    pub fn flash_loan(
+        &mut self,
+        receiver: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        if self.read_flash_loan_in_progress() {
+            return Err(Error::FlashLoanInProgress);
+        }
+        if amount > self.max_flash_loan() {
+            return Err(Error::FlashLoanTooLarge);
+        }
+
+        self.write_flash_loan_in_progress(true);
+        let result = self.lend_flash_loan(receiver, amount, data);
+        self.write_flash_loan_in_progress(false);
+        result
+    }
+
+    /// Lends `amount` to `receiver` and takes it back along with the fee, once the checks of
+    /// [`ERC20::flash_loan`] have passed.
+    fn lend_flash_loan(
+        &mut self,
+        receiver: Address,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        let initiator = self.storage.caller()?;
+        let fee = self.flash_fee(amount)?;
+        let repayment = amount.checked_add(fee).ok_or(Error::Overflow)?;
+        let fee_recipient = self.read_flash_fee_recipient();
+        if let Some(fee_recipient) = fee_recipient {
+            if !fee.is_zero() {
+                self.ensure_not_frozen(fee_recipient)?;
+            }
+        }
+
+        self.mint(receiver, amount)?;
+        receiver::notify_flash_loan(receiver, initiator, amount, fee, data)?;
+
+        let token = Address::Contract(self.storage.contract_package_hash()?);
+        let new_allowance = self
+            .read_unexpired_allowance(receiver, token)?
+            .checked_sub(repayment)
+            .ok_or(Error::InsufficientAllowance)?;
+        self.write_allowance(receiver, token, new_allowance);
+
+        match fee_recipient {
+            Some(fee_recipient) if !fee.is_zero() => {
+                self.burn(receiver, amount)?;
+                self.transfer_balance(receiver, fee_recipient, fee)?;
+                self.emit(Event::Transfer {
+                    sender: receiver,
+                    recipient: fee_recipient,
+                    amount: fee,
+                });
+                Ok(())
+            }
+            _ => self.burn(receiver, repayment),
+        }
+    }
+
+    /// Sets the flash loan fee to `basis_points` hundredths of a percent of the loan.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_flash_fee(&mut self, basis_points: u32) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        self.write_flash_fee_basis_points(basis_points);
+        Ok(())
+    }
+
+    /// Sets the account receiving flash loan fees, or makes the fees burned if `recipient` is
+    /// `None`.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn set_flash_fee_recipient(&mut self, recipient: Option<Address>) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        self.write_flash_fee_recipient(recipient);
+        Ok(())
+    }
+
+    /// Returns the maximum total supply of the token, or `None` if the supply is not capped.
+    pub fn cap(&self) -> Option<U256> {
+        self.read_cap()
+    }
+
+    /// Caps the total supply of the token at `cap`.
+    ///
+    /// Returns [`Error::InvalidCap`] if `cap` is below the current total supply.
+    ///
+    /// # Security
+    ///
+    /// This offers no security whatsoever, and is meant to be called on the instance returned by
+    /// an `install` method. It is advised to NOT expose this method through a public entry point,
+    /// and to use [`ERC20::lower_cap`] instead.
+    pub fn set_cap(&mut self, cap: U256) -> Result<(), Error> {
+        if cap < self.read_total_supply() {
+            return Err(Error::InvalidCap);
+        }
+        self.write_cap(Some(cap));
+        Ok(())
+    }
+
+    /// Lowers the maximum total supply of the token to `cap`.
+    ///
+    /// The cap can never be raised, nor set below the current total supply. The direct caller has
+    /// to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn lower_cap(&mut self, cap: U256) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        if let Some(current_cap) = self.read_cap() {
+            if cap > current_cap {
+                return Err(Error::InvalidCap);
+            }
+        }
+        self.set_cap(cap)
+    }
+
+    /// Returns the ID of the most recent snapshot, or 0 if no snapshot has been taken yet.
+    pub fn current_snapshot_id(&self) -> u64 {
+        self.read_snapshot_id()
+    }
+
+    /// Takes a snapshot of all balances and the total supply, and returns its ID.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn snapshot(&mut self) -> Result<u64, Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        let snapshot_id = self
+            .read_snapshot_id()
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        self.write_snapshot_id(snapshot_id);
+        Ok(snapshot_id)
+    }
+
+    /// Returns the balance of `owner` at the time the snapshot with `snapshot_id` was taken.
+    pub fn balance_of_at(&self, owner: Address, snapshot_id: u64) -> Result<U256, Error> {
+        self.ensure_snapshot_taken(snapshot_id)?;
+        let balance = snapshots::read_balance_snapshot_from(&self.storage, snapshot_id, owner);
+        Ok(balance.unwrap_or_else(|| self.read_balance(owner)))
+    }
+
+    /// Returns the total supply of the token at the time the snapshot with `snapshot_id` was
+    /// taken.
+    pub fn total_supply_at(&self, snapshot_id: u64) -> Result<U256, Error> {
+        self.ensure_snapshot_taken(snapshot_id)?;
+        let total_supply = snapshots::read_total_supply_snapshot_from(&self.storage, snapshot_id);
+        Ok(total_supply.unwrap_or_else(|| self.read_total_supply()))
+    }
+
+    /// Returns the account `delegator` has delegated its votes to, if any.
+    pub fn delegates(&self, delegator: Address) -> Option<Address> {
+        self.read_delegate(delegator)
+    }
+
+    /// Delegates the votes of the direct caller, both current and backed by tokens received in
+    /// the future, to `delegatee`.
+    ///
+    /// Tokens do not count as votes until they are delegated, hence holders who want to vote
+    /// themselves have to delegate to their own address.
+    pub fn delegate(&mut self, delegatee: Address) -> Result<(), Error> {
+        let delegator = self.storage.caller()?;
+        let previous_delegatee = self.read_delegate(delegator);
+        self.write_delegate(delegator, delegatee);
+        let balance = self.read_balance(delegator);
+        self.move_votes(previous_delegatee, Some(delegatee), balance)
+    }
+
+    /// Returns the current voting power of `address`.
+    pub fn get_votes(&self, address: Address) -> U256 {
+        votes::read_votes_from(&self.storage, address)
+    }
+
+    /// Returns the voting power of `address` at the end of `block_time`.
+    ///
+    /// Returns [`Error::InvalidBlockTime`] unless `block_time` is in the past, as the voting power
+    /// can still change within the current block.
+    pub fn get_past_votes(&self, address: Address, block_time: u64) -> Result<U256, Error> {
+        if block_time >= self.storage.block_time() {
+            return Err(Error::InvalidBlockTime);
+        }
+        Ok(votes::read_past_votes_from(
+            &self.storage,
+            address,
+            block_time,
+        ))
+    }
+
+    /// Returns `true` if `address` is frozen.
+    pub fn is_frozen(&self, address: Address) -> bool {
+        self.read_frozen(address)
+    }
+
+    /// Prevents `address` from sending, receiving or spending tokens.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn freeze(&mut self, address: Address) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        self.write_frozen(address, true);
+        Ok(())
+    }
+
+    /// Allows a previously frozen `address` to send, receive and spend tokens again.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn unfreeze(&mut self, address: Address) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        self.write_frozen(address, false);
+        Ok(())
+    }
+
+    /// Returns `true` if the token is paused.
+    pub fn is_paused(&self) -> bool {
+        self.read_paused()
+    }
+
+    /// Halts transfers, approvals, minting and burning of the token.
+    ///
+    /// The direct caller has to hold the [`PAUSER_ROLE`](constants::PAUSER_ROLE).
+    pub fn pause(&mut self) -> Result<(), Error> {
+        self.ensure_role(PAUSER_ROLE)?;
+        self.write_paused(true);
         Ok(())
     }
 
+    /// Resumes transfers, approvals, minting and burning of the token.
+    ///
+    /// The direct caller has to hold the [`PAUSER_ROLE`](constants::PAUSER_ROLE).
+    pub fn unpause(&mut self) -> Result<(), Error> {
+        self.ensure_role(PAUSER_ROLE)?;
+        self.write_paused(false);
+        Ok(())
+    }
+
+    /// Allows `spender` to transfer up to `amount` of tokens owned by the holder of `public_key`,
+    /// without requiring the owner to pay for a deploy.
+    ///
+    /// `signature` is a serialized [`Signature`] made with `public_key` over the message returned
+    /// by [`permit_message`] for the owner's current nonce, passed through
+    /// [`namespaced_permit_message`] if the token is hosted alongside others. The permit is
+    /// rejected once the block time passes `deadline`. Returns [`Error::InvalidConfiguration`] if
+    /// the token was installed without a [chain name](ERC20Builder::with_chain_name), as permits
+    /// would not be bound to any network.
+    pub fn permit(
+        &mut self,
+        public_key: PublicKey,
+        spender: Address,
+        amount: U256,
+        deadline: u64,
+        signature: Bytes,
+    ) -> Result<(), Error> {
+        self.ensure_not_paused()?;
+        if self.storage.block_time() > deadline {
+            return Err(Error::PermitExpired);
+        }
+
+        let chain_name = self.chain_name();
+        if chain_name.is_empty() {
+            return Err(Error::InvalidConfiguration);
+        }
+
+        let owner = Address::from(public_key.to_account_hash());
+        let nonce = self.read_nonce(owner);
+        let message = permit_message(
+            self.storage.contract_package_hash()?,
+            &chain_name,
+            owner,
+            spender,
+            amount,
+            nonce,
+            deadline,
+        )
+        .and_then(|message| match self.storage.namespace() {
+            Some(token_id) => namespaced_permit_message(message, token_id),
+            None => Ok(message),
+        })
+        .map_err(|_| Error::InvalidSignature)?;
+        let signature: Signature =
+            bytesrepr::deserialize(signature.into()).map_err(|_| Error::InvalidSignature)?;
+        crypto::verify(&message, &signature, &public_key).map_err(|_| Error::InvalidSignature)?;
+
+        self.write_nonce(owner, nonce + 1);
+        self.write_approval(owner, spender, amount, None);
+        Ok(())
+    }
+
+    /// Returns the nonce which has to be signed by `owner` in its next permit.
+    pub fn nonce_of(&self, owner: Address) -> u64 {
+        self.read_nonce(owner)
+    }
+
+    /// Returns the name of the chain which permits are bound to, set at installation.
+    ///
+    /// An empty name means the token does not accept permits.
+    pub fn chain_name(&self) -> String {
+        permit::read_chain_name_from(&self.storage)
+    }
+
+    /// Returns the event recorded under `index`.
+    pub fn event(&self, index: u64) -> Option<Event> {
+        events::read_event_from(&self.storage, index)
+    }
+
+    /// Returns the number of events recorded so far.
+    pub fn events_count(&self) -> u64 {
+        events::read_events_count_from(&self.storage)
+    }
+
+    /// Returns `true` if `account` has been granted `role`.
+    pub fn has_role(&self, role: &str, account: Address) -> bool {
+        self.read_role(role, account)
+    }
+
+    /// Ensures that the direct caller has been granted `role`, and returns its address.
+    ///
+    /// Contracts can use this to guard entry points which expose otherwise unsecured methods such
+    /// as [`ERC20::mint`] or [`ERC20::burn`].
+    pub fn ensure_role(&self, role: &str) -> Result<Address, Error> {
+        let caller = self.storage.caller()?;
+        if !self.read_role(role, caller) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(caller)
+    }
+
+    /// Grants `role` to `account`.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn grant_role(&mut self, role: &str, account: Address) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        self.write_role(role, account, true);
+        Ok(())
+    }
+
+    /// Revokes `role` from `account`.
+    ///
+    /// The direct caller has to hold the [`ADMIN_ROLE`](constants::ADMIN_ROLE).
+    pub fn revoke_role(&mut self, role: &str, account: Address) -> Result<(), Error> {
+        self.ensure_role(ADMIN_ROLE)?;
+        self.write_role(role, account, false);
+        Ok(())
+    }
+
+    /// Revokes `role` from the direct caller.
+    pub fn renounce_role(&mut self, role: &str) -> Result<(), Error> {
+        let caller = self.storage.caller()?;
+        self.write_role(role, caller, false);
+        Ok(())
+    }
+
+    /// Mints `amount` tokens to `recipient` against an inbound bridge request.
+    ///
+    /// A request is identified by `origin_chain_id`, `tx_hash` and `log_index` of the event which
+    /// locked the tokens on the origin chain, and it can be processed only once. The direct caller
+    /// has to hold the [`MINTER_ROLE`](constants::MINTER_ROLE).
+    pub fn bridge_mint(
+        &mut self,
+        recipient: Address,
+        amount: U256,
+        origin_chain_id: u64,
+        tx_hash: &str,
+        log_index: u64,
+    ) -> Result<(), Error> {
+        self.ensure_role(MINTER_ROLE)?;
+        if self.is_bridge_request_processed(origin_chain_id, tx_hash, log_index) {
+            return Err(Error::DuplicateBridgeRequest);
+        }
+        self.mint(recipient, amount)?;
+        bridge::write_bridge_request_to(&mut self.storage, origin_chain_id, tx_hash, log_index);
+        Ok(())
+    }
+
+    /// Returns `true` if an inbound bridge request has already been processed.
+    pub fn is_bridge_request_processed(
+        &self,
+        origin_chain_id: u64,
+        tx_hash: &str,
+        log_index: u64,
+    ) -> bool {
+        bridge::read_bridge_request_from(&self.storage, origin_chain_id, tx_hash, log_index)
+    }
+
+    /// Burns `amount` of the direct caller's tokens and records a request to release them to
+    /// `receiver` on the chain identified by `to_chain_id`.
+    ///
+    /// Returns the index under which the request was recorded for relayers.
+    pub fn request_bridge_back(
+        &mut self,
+        amount: U256,
+        to_chain_id: u64,
+        receiver: Bytes,
+    ) -> Result<u64, Error> {
+        let sender = self.storage.caller()?;
+        self.burn(sender, amount)?;
+        let request = BridgeBackRequest {
+            sender,
+            amount,
+            to_chain_id,
+            receiver,
+        };
+        let index = bridge::append_bridge_back_request_to(&mut self.storage, request);
+        Ok(index)
+    }
+
+    /// Returns the outbound bridge request recorded under `index`.
+    pub fn bridge_back_request(&self, index: u64) -> Option<BridgeBackRequest> {
+        bridge::read_bridge_back_request_from(&self.storage, index)
+    }
+
+    /// Returns the number of outbound bridge requests recorded so far.
+    pub fn bridge_back_requests_count(&self) -> u64 {
+        bridge::read_bridge_back_requests_count_from(&self.storage)
+    }
+
+    /// Sets `owner` up as the holder of the `initial_supply` and of the administrative roles of a
+    /// newly created token.
+    fn set_up(&mut self, owner: Address, initial_supply: U256) -> Result<(), Error> {
+        // The owner administers the token, and is allowed to mint and pause it.
+        for role in &[ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE] {
+            self.write_role(role, owner, true);
+        }
+        // The initial supply is recorded as minted to the owner.
+        self.mint(owner, initial_supply)
+    }
+}
+
+impl ERC20 {
+    /// Installs the ERC20 contract with the default set of entry points.
+    ///
+    /// This should be called from within `fn call()` of your contract. Use [`ERC20Builder`] to
+    /// configure the installation further, such as to bind permits to a chain with
+    /// [`ERC20Builder::with_chain_name`].
+    pub fn install(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+    ) -> Result<ERC20, Error> {
+        ERC20Builder::new(name, symbol, decimals)
+            .with_initial_supply(initial_supply)
+            .install()
+    }
+
     /// Installs the ERC20 contract with a custom set of entry points.
     ///
     /// # Warning
@@ -242,6 +1160,7 @@ impl ERC20 {
     /// of ERC20 entry points. Using `install_custom` with a different set of entry points might
     /// lead to problems with integrators such as wallets, and exchanges.
     #[doc(hidden)]
+    #[deprecated(note = "use `ERC20Builder` instead")]
     pub fn install_custom(
         name: String,
         symbol: String,
@@ -250,63 +1169,361 @@ impl ERC20 {
         contract_key_name: &str,
         entry_points: EntryPoints,
     ) -> Result<ERC20, Error> {
-        let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
-        let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
-        // We need to hold on a RW access rights because tokens can be minted or burned.
-        let total_supply_uref = storage::new_uref(initial_supply).into_read_write();
+        ERC20Builder::new(name, symbol, decimals)
+            .with_initial_supply(initial_supply)
+            .with_contract_key_name(contract_key_name)
+            .with_entry_points(entry_points)
+            .install()
+    }
 
-        let mut named_keys = NamedKeys::new();
+    /// Installs the ERC20 contract as the first version of an upgradeable contract package.
+    ///
+    /// Besides the contract hash stored under `contract_key_name`, the caller's named keys will
+    /// hold:
+    ///
+    /// * `<contract_key_name>_package_hash` - the hash of the contract package,
+    /// * `<contract_key_name>_access_token` - the access [`URef`] required to add new versions,
+    /// * `<contract_key_name>_version` - the version of the active contract,
+    /// * `<contract_key_name>_named_keys` - the named keys shared by all versions of the contract.
+    ///
+    /// New versions are added with [`ERC20::upgrade`].
+    pub fn install_upgradeable(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<ERC20, Error> {
+        ERC20Builder::new(name, symbol, decimals)
+            .with_initial_supply(initial_supply)
+            .with_contract_key_name(contract_key_name)
+            .with_entry_points(entry_points)
+            .upgradeable()
+            .install()
+    }
 
-        let name_key = {
-            let name_uref = storage::new_uref(name).into_read();
-            Key::from(name_uref)
+    /// Adds a new version of a contract previously installed with [`ERC20::install_upgradeable`]
+    /// under `contract_key_name`.
+    ///
+    /// The new version reuses the named keys of the installed contract, along with the ones of the
+    /// tokens created since with [`ERC20::create_token`], so the balances, allowances and total
+    /// supply are preserved. This should be called from within `fn call()` of a session executed
+    /// by the account which installed the contract.
+    pub fn upgrade(contract_key_name: &str, entry_points: EntryPoints) {
+        let contract_package_hash = {
+            let key = runtime::get_key(&detail::package_hash_key_name(contract_key_name))
+                .ok_or(ApiError::MissingKey)
+                .unwrap_or_revert();
+            key.into_hash()
+                .map(ContractPackageHash::new)
+                .ok_or(ApiError::UnexpectedKeyVariant)
+                .unwrap_or_revert()
         };
+        let named_keys: NamedKeys =
+            detail::read_from(&detail::named_keys_key_name(contract_key_name));
 
-        let symbol_key = {
-            let symbol_uref = storage::new_uref(symbol).into_read();
-            Key::from(symbol_uref)
-        };
+        let (contract_hash, contract_version) =
+            storage::add_contract_version(contract_package_hash, entry_points, named_keys);
 
-        let decimals_key = {
-            let decimals_uref = storage::new_uref(decimals).into_read();
-            Key::from(decimals_uref)
-        };
+        runtime::put_key(contract_key_name, Key::from(contract_hash));
+        let version_uref = detail::get_uref(&detail::version_key_name(contract_key_name));
+        storage::write(version_uref, contract_version);
+    }
+
+    /// Returns the token identified by `token_id` among the tokens hosted by the current contract.
+    ///
+    /// Methods of the returned token revert if no such token has been installed or created.
+    pub fn namespaced(token_id: &str) -> ERC20 {
+        ERC20::new(ContractStorage::namespaced(token_id))
+    }
+
+    /// Returns the identifiers of the tokens hosted by the current contract, in order of
+    /// creation.
+    ///
+    /// A contract installed without a token id hosts a single token, and returns no identifiers.
+    pub fn token_ids() -> Vec<String> {
+        match runtime::get_key(TOKEN_IDS_KEY_NAME) {
+            Some(_) => detail::read_from(TOKEN_IDS_KEY_NAME),
+            None => Vec::new(),
+        }
+    }
+
+    /// Creates a token identified by `token_id` alongside the tokens already hosted by the current
+    /// contract, which has to be installed with a token id.
+    ///
+    /// The immediate caller is granted the administrative roles of the new token, and receives its
+    /// `initial_supply`. Permits of the new token are bound to the same chain as the ones of the
+    /// first hosted token. Returns [`Error::InvalidConfiguration`] if the token id is empty or
+    /// contains `_`, if the name or the symbol is empty, if the token id is already taken, if any
+    /// named key of the new token already exists, or if the contract was installed without a
+    /// token id.
+    ///
+    /// If the contract is upgradeable, the named keys of the new token are also handed over to
+    /// versions added later with [`ERC20::upgrade`].
+    ///
+    /// # Security
+    ///
+    /// This offers no security whatsoever, hence it is advised to guard the entry point calling it
+    /// with a role of an already hosted token.
+    pub fn create_token(
+        token_id: &str,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+    ) -> Result<ERC20, Error> {
+        if !detail::is_valid_token_id(token_id) || name.is_empty() || symbol.is_empty() {
+            return Err(Error::InvalidConfiguration);
+        }
+        let mut token_ids = Self::token_ids();
+        if token_ids.is_empty()
+            || token_ids
+                .iter()
+                .any(|hosted_token_id| hosted_token_id == token_id)
+        {
+            return Err(Error::InvalidConfiguration);
+        }
+        let caller = detail::get_immediate_caller_address()?;
+        let chain_name = ERC20::namespaced(&token_ids[0]).chain_name();
 
-        let total_supply_key = Key::from(total_supply_uref);
+        let named_keys =
+            Self::create_token_named_keys(name, symbol, decimals, chain_name, Some(token_id));
+        if named_keys.keys().any(|name| runtime::has_key(name)) {
+            return Err(Error::InvalidConfiguration);
+        }
+        for (name, key) in &named_keys {
+            runtime::put_key(name, *key);
+        }
+        if runtime::has_key(SHARED_NAMED_KEYS_KEY_NAME) {
+            let mut shared_named_keys: NamedKeys = detail::read_from(SHARED_NAMED_KEYS_KEY_NAME);
+            shared_named_keys.extend(named_keys);
+            storage::write(
+                detail::get_uref(SHARED_NAMED_KEYS_KEY_NAME),
+                shared_named_keys,
+            );
+        }
+        token_ids.push(token_id.to_string());
+        storage::write(detail::get_uref(TOKEN_IDS_KEY_NAME), token_ids);
 
-        let balances_dictionary_key = {
-            // Sets up initial balance for the caller - either an account, or a contract.
-            let caller = detail::get_caller_address()?;
-            balances::write_balance_to(balances_uref, caller, initial_supply);
+        let mut erc20 = ERC20::namespaced(token_id);
+        erc20.set_up(caller, initial_supply)?;
+        Ok(erc20)
+    }
 
-            runtime::remove_key(BALANCES_KEY_NAME);
+    /// Creates the named keys holding the state of a new token, prefixed with `namespace` if the
+    /// token is hosted alongside others.
+    fn create_token_named_keys(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        chain_name: String,
+        namespace: Option<&str>,
+    ) -> NamedKeys {
+        let mut named_keys = NamedKeys::new();
+
+        for dictionary_name in &[
+            BALANCES_KEY_NAME,
+            ALLOWANCES_KEY_NAME,
+            ALLOWANCE_EXPIRIES_KEY_NAME,
+            ROLES_KEY_NAME,
+            BRIDGE_REQUESTS_KEY_NAME,
+            BRIDGE_BACK_REQUESTS_KEY_NAME,
+            EVENTS_KEY_NAME,
+            NONCES_KEY_NAME,
+            FROZEN_KEY_NAME,
+            BALANCE_SNAPSHOTS_KEY_NAME,
+            TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+            DELEGATES_KEY_NAME,
+            VOTE_CHECKPOINTS_KEY_NAME,
+        ] {
+            let key_name = detail::namespaced_key_name(namespace, dictionary_name);
+            let dictionary_uref = storage::new_dictionary(&key_name).unwrap_or_revert();
+            // Dictionaries are created under the named keys of the current context, and are
+            // handed over to the contract along with the other named keys.
+            runtime::remove_key(&key_name);
+            named_keys.insert(key_name, Key::from(dictionary_uref));
+        }
 
-            Key::from(balances_uref)
+        let mut insert = |key_name: &str, uref: URef| {
+            named_keys.insert(
+                detail::namespaced_key_name(namespace, key_name),
+                Key::from(uref),
+            );
         };
+        // The name and the symbol are writable, so the token can be renamed.
+        insert(NAME_KEY_NAME, storage::new_uref(name).into_read_write());
+        insert(SYMBOL_KEY_NAME, storage::new_uref(symbol).into_read_write());
+        insert(DECIMALS_KEY_NAME, storage::new_uref(decimals).into_read());
+        // We need to hold on a RW access rights because tokens can be minted or burned. The initial
+        // supply is minted once the token is set up.
+        insert(
+            TOTAL_SUPPLY_KEY_NAME,
+            storage::new_uref(U256::zero()).into_read_write(),
+        );
+        insert(
+            BRIDGE_BACK_REQUESTS_COUNT_KEY_NAME,
+            storage::new_uref(0u64).into_read_write(),
+        );
+        insert(
+            EVENTS_COUNT_KEY_NAME,
+            storage::new_uref(0u64).into_read_write(),
+        );
+        // The token starts unpaused.
+        insert(PAUSED_KEY_NAME, storage::new_uref(false).into_read_write());
+        // Permits stay bound to the chain the token is installed on.
+        insert(
+            CHAIN_NAME_KEY_NAME,
+            storage::new_uref(chain_name).into_read(),
+        );
+        // The supply is not capped unless a cap is set after installation.
+        insert(
+            CAP_KEY_NAME,
+            storage::new_uref(Option::<U256>::None).into_read_write(),
+        );
+        // No snapshot is taken at installation.
+        insert(
+            SNAPSHOT_ID_KEY_NAME,
+            storage::new_uref(0u64).into_read_write(),
+        );
+        // Flash loans are free and their fees are burned until configured otherwise.
+        insert(
+            FLASH_FEE_BASIS_POINTS_KEY_NAME,
+            storage::new_uref(0u32).into_read_write(),
+        );
+        insert(
+            FLASH_FEE_RECIPIENT_KEY_NAME,
+            storage::new_uref(Option::<Address>::None).into_read_write(),
+        );
+        // Nested flash loans are rejected while this is set.
+        insert(
+            FLASH_LOAN_IN_PROGRESS_KEY_NAME,
+            storage::new_uref(false).into_read_write(),
+        );
+        // The metadata is empty unless configured at installation.
+        insert(
+            METADATA_KEY_NAME,
+            storage::new_uref(TokenMetadata::default()).into_read_write(),
+        );
 
-        let allowances_dictionary_key = {
-            runtime::remove_key(ALLOWANCES_KEY_NAME);
+        named_keys
+    }
 
-            Key::from(allowances_uref)
-        };
+    /// Installs a token configured by `builder`, which has already been validated.
+    fn install_contract(builder: ERC20Builder) -> Result<ERC20, Error> {
+        let entry_points = builder.build_entry_points();
+        let ERC20Builder {
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            initial_holders,
+            chain_name,
+            contract_key_name,
+            named_keys: extra_named_keys,
+            upgradeable,
+            cap,
+            token_id,
+            metadata: token_metadata,
+            ..
+        } = builder;
+        let contract_key_name = contract_key_name.as_str();
 
-        named_keys.insert(NAME_KEY_NAME.to_string(), name_key);
-        named_keys.insert(SYMBOL_KEY_NAME.to_string(), symbol_key);
-        named_keys.insert(DECIMALS_KEY_NAME.to_string(), decimals_key);
-        named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
-        named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), allowances_dictionary_key);
-        named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+        // The caller - either an account, or a contract.
+        let caller = detail::get_caller_address()?;
 
-        let (contract_hash, _version) =
-            storage::new_locked_contract(entry_points, Some(named_keys), None, None);
+        let mut named_keys = extra_named_keys;
+        named_keys.append(&mut Self::create_token_named_keys(
+            name,
+            symbol,
+            decimals,
+            chain_name,
+            token_id.as_deref(),
+        ));
+        if let Some(token_id) = &token_id {
+            // The registry lists the tokens hosted by the contract, starting with this one.
+            let token_ids_uref = storage::new_uref(vec![token_id.clone()]).into_read_write();
+            named_keys.insert(TOKEN_IDS_KEY_NAME.to_string(), Key::from(token_ids_uref));
+        }
+
+        // Named keys of the contract are not reachable from the installing context.
+        let contract_storage = ContractStorage::with_named_keys(&named_keys, token_id.as_deref());
+
+        let contract_hash = if upgradeable {
+            // Named keys are kept by the installer so they can be handed over to new versions. The
+            // contract holds them as well, so it can add the ones of the tokens it creates.
+            let named_keys_uref = storage::new_uref(NamedKeys::new());
+            named_keys.insert(
+                SHARED_NAMED_KEYS_KEY_NAME.to_string(),
+                Key::from(named_keys_uref),
+            );
+            storage::write(named_keys_uref, named_keys.clone());
+
+            let (contract_hash, contract_version) = storage::new_contract(
+                entry_points,
+                Some(named_keys),
+                Some(detail::package_hash_key_name(contract_key_name)),
+                Some(detail::access_token_key_name(contract_key_name)),
+            );
+
+            runtime::put_key(
+                &detail::named_keys_key_name(contract_key_name),
+                Key::from(named_keys_uref),
+            );
+
+            let version_uref = storage::new_uref(contract_version);
+            runtime::put_key(
+                &detail::version_key_name(contract_key_name),
+                Key::from(version_uref),
+            );
+
+            contract_hash
+        } else {
+            let (contract_hash, _version) =
+                storage::new_locked_contract(entry_points, Some(named_keys), None, None);
+            contract_hash
+        };
 
         // Hash of the installed contract will be reachable through named keys.
         runtime::put_key(contract_key_name, Key::from(contract_hash));
 
-        Ok(ERC20::new(
-            balances_uref,
-            allowances_uref,
-            total_supply_uref,
-        ))
+        let mut erc20 = ERC20::new(contract_storage);
+        erc20.set_up(caller, initial_supply)?;
+        for (holder, amount) in initial_holders {
+            erc20.mint(holder, amount)?;
+        }
+        if let Some(cap) = cap {
+            erc20.set_cap(cap)?;
+        }
+        if let Some(token_metadata) = token_metadata {
+            metadata::write_metadata_to(&mut erc20.storage, token_metadata);
+        }
+
+        Ok(erc20)
+    }
+}
+
+#[cfg(feature = "in-memory")]
+impl ERC20<MemoryStorage> {
+    /// Creates a token kept in memory, set up as if it was installed by `owner`.
+    ///
+    /// This is meant for testing the rules of the token natively. `owner` is also set as the
+    /// direct caller of the returned token's storage.
+    pub fn in_memory(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: U256,
+        owner: Address,
+    ) -> Result<Self, Error> {
+        let mut storage = MemoryStorage::default();
+        storage.write_named_value(NAME_KEY_NAME, name);
+        storage.write_named_value(SYMBOL_KEY_NAME, symbol);
+        storage.write_named_value(DECIMALS_KEY_NAME, decimals);
+        storage.set_caller(owner);
+
+        let mut erc20 = ERC20::new(storage);
+        erc20.set_up(owner, initial_supply)?;
+        Ok(erc20)
     }
 }
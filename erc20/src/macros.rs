@@ -0,0 +1,207 @@
+//! Implementation of a macro generating the entry points of a token, along with the functions it
+//! expands to.
+use alloc::string::String;
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, CLValue,
+};
+
+use crate::{constants::TOKEN_ID_RUNTIME_ARG_NAME, error::Error, ERC20};
+
+/// Returns the value of the runtime argument `name`.
+pub fn get_named_arg<T: CLTyped + FromBytes>(name: &str) -> T {
+    runtime::get_named_arg(name)
+}
+
+/// Returns the token selected by the `token_id` runtime argument, among the tokens hosted by the
+/// current contract.
+pub fn namespaced_token() -> ERC20 {
+    let token_id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    ERC20::namespaced(&token_id)
+}
+
+/// Returns `value` to the caller of the entry point.
+pub fn ret<T: CLTyped + ToBytes>(value: T) -> ! {
+    runtime::ret(CLValue::from_t(value).unwrap_or_revert())
+}
+
+/// Returns the value of `result`, or reverts with its error.
+pub fn unwrap_or_revert<T>(result: Result<T, Error>) -> T {
+    result.unwrap_or_revert()
+}
+
+/// Generates the `#[no_mangle]` functions of the [default set](crate::entry_points::default) of
+/// entry points for a token type.
+///
+/// The token type has to implement [`Default`], and be either [`ERC20`](crate::ERC20) itself or a
+/// type wrapping it which implements both [`Deref`](core::ops::Deref) and
+/// [`DerefMut`](core::ops::DerefMut) to [`ERC20`](crate::ERC20), as functions such as `transfer`
+/// or `approve` call methods taking `&mut self`. Each function reads the runtime arguments named
+/// in [`constants`](crate::constants), calls the method of the same name on a default instance of
+/// the token, and returns its result or reverts with its error.
+///
+/// Passing `mint_burn_authorization` additionally generates the `mint` and `burn` functions, which
+/// take the `owner` and `amount` runtime arguments. They call the given hook, of type
+/// `fn(&Token) -> Result<(), Error>`, with the token before minting or burning, and revert with
+/// the error it returns. The entry points of these functions make up the
+/// [mintable set](crate::entry_points::mintable).
+///
+/// ```ignore
+/// casper_erc20::erc20_entry_points!(
+///     ERC20,
+///     mint_burn_authorization = |token: &ERC20| token.ensure_role(MINTER_ROLE).map(|_| ())
+/// );
+/// ```
+///
+/// Passing `namespaced` instead of a token type generates the functions of the
+/// [namespaced default set](crate::entry_points::namespaced_default) of entry points, for a
+/// contract hosting several tokens. Each function calls the method on the token selected by the
+/// `token_id` runtime argument, and the `token_ids` function returns the hosted tokens.
+///
+/// ```ignore
+/// casper_erc20::erc20_entry_points!(namespaced);
+/// ```
+///
+/// Functions of the entry points added by the optional features of
+/// [`ERC20Builder`](crate::ERC20Builder), such as `pause` or `grant_role`, are not generated, and
+/// have to be written by the contract.
+#[macro_export]
+macro_rules! erc20_entry_points {
+    (namespaced) => {
+        $crate::erc20_entry_points!(@token $crate::macros::namespaced_token());
+        $crate::erc20_entry_points!(@registry);
+    };
+    (namespaced, mint_burn_authorization = $authorize:expr) => {
+        $crate::erc20_entry_points!(
+            @token $crate::macros::namespaced_token(),
+            mint_burn_authorization = $authorize
+        );
+        $crate::erc20_entry_points!(@registry);
+    };
+    (@registry) => {
+        #[no_mangle]
+        pub extern "C" fn token_ids() {
+            let token_ids = $crate::ERC20::token_ids();
+            $crate::macros::ret(token_ids);
+        }
+    };
+    (@token $make:expr) => {
+        #[no_mangle]
+        pub extern "C" fn name() {
+            let name = $make.name();
+            $crate::macros::ret(name);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn symbol() {
+            let symbol = $make.symbol();
+            $crate::macros::ret(symbol);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn decimals() {
+            let decimals = $make.decimals();
+            $crate::macros::ret(decimals);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn total_supply() {
+            let total_supply = $make.total_supply();
+            $crate::macros::ret(total_supply);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn balance_of() {
+            let address: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::ADDRESS_RUNTIME_ARG_NAME);
+            let balance = $make.balance_of(address);
+            $crate::macros::ret(balance);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn transfer() {
+            let recipient: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            $crate::macros::unwrap_or_revert($make.transfer(recipient, amount));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn approve() {
+            let spender: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            $crate::macros::unwrap_or_revert($make.approve(spender, amount));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn allowance() {
+            let owner: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let spender: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let allowance = $make.allowance(owner, spender);
+            $crate::macros::ret(allowance);
+        }
+
+        #[no_mangle]
+        pub extern "C" fn transfer_from() {
+            let owner: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let recipient: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::RECIPIENT_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            $crate::macros::unwrap_or_revert($make.transfer_from(owner, recipient, amount));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn increase_allowance() {
+            let spender: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            $crate::macros::unwrap_or_revert($make.increase_allowance(spender, amount));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn decrease_allowance() {
+            let spender: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::SPENDER_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            $crate::macros::unwrap_or_revert($make.decrease_allowance(spender, amount));
+        }
+    };
+    (@token $make:expr, mint_burn_authorization = $authorize:expr) => {
+        $crate::erc20_entry_points!(@token $make);
+
+        #[no_mangle]
+        pub extern "C" fn mint() {
+            let owner: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let mut token = $make;
+            $crate::macros::unwrap_or_revert(($authorize)(&token));
+            $crate::macros::unwrap_or_revert(token.mint(owner, amount));
+        }
+
+        #[no_mangle]
+        pub extern "C" fn burn() {
+            let owner: $crate::Address =
+                $crate::macros::get_named_arg($crate::constants::OWNER_RUNTIME_ARG_NAME);
+            let amount = $crate::macros::get_named_arg($crate::constants::AMOUNT_RUNTIME_ARG_NAME);
+            let mut token = $make;
+            $crate::macros::unwrap_or_revert(($authorize)(&token));
+            $crate::macros::unwrap_or_revert(token.burn(owner, amount));
+        }
+    };
+    ($token:ty) => {
+        $crate::erc20_entry_points!(@token <$token>::default());
+    };
+    ($token:ty, mint_burn_authorization = $authorize:expr) => {
+        $crate::erc20_entry_points!(
+            @token <$token>::default(),
+            mint_burn_authorization = $authorize
+        );
+    };
+}
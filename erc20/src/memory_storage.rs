@@ -0,0 +1,137 @@
+//! Implementation of an in-memory storage backend.
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, CLValue, ContractPackageHash, U256,
+};
+
+use crate::{backend::Storage, error::Error, Address};
+
+/// Storage which keeps the state of a token in memory, so its rules can be tested natively without
+/// installing it as a contract.
+///
+/// It is only available with the `in-memory` feature, which test suites enable.
+///
+/// The direct caller, the block time and the contract package hash are set explicitly. Methods
+/// which call other contracts, such as [`ERC20::transfer_and_call`](crate::ERC20::transfer_and_call)
+/// or [`ERC20::flash_loan`](crate::ERC20::flash_loan), still require a contract runtime.
+///
+/// # Panics
+///
+/// Reading a value as a different type than it was written with panics, where a contract would
+/// revert.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    balances: BTreeMap<Address, U256>,
+    allowances: BTreeMap<(Address, Address), U256>,
+    total_supply: U256,
+    named_values: BTreeMap<String, CLValue>,
+    dictionaries: BTreeMap<(String, String), CLValue>,
+    caller: Option<Address>,
+    block_time: u64,
+    contract_package_hash: Option<ContractPackageHash>,
+}
+
+impl MemoryStorage {
+    /// Sets the address returned as the direct caller.
+    pub fn set_caller(&mut self, caller: Address) {
+        self.caller = Some(caller);
+    }
+
+    /// Sets the current block time, in milliseconds.
+    pub fn set_block_time(&mut self, block_time: u64) {
+        self.block_time = block_time;
+    }
+
+    /// Sets the hash of the contract package the token pretends to be installed as.
+    pub fn set_contract_package_hash(&mut self, contract_package_hash: ContractPackageHash) {
+        self.contract_package_hash = Some(contract_package_hash);
+    }
+}
+
+fn to_cl_value<T: CLTyped + ToBytes>(value: T) -> CLValue {
+    CLValue::from_t(value).expect("should serialize value")
+}
+
+fn from_cl_value<T: CLTyped + FromBytes>(cl_value: &CLValue) -> T {
+    cl_value
+        .clone()
+        .into_t()
+        .expect("should deserialize value of the same type")
+}
+
+impl Storage for MemoryStorage {
+    fn read_balance(&self, owner: Address) -> U256 {
+        self.balances.get(&owner).copied().unwrap_or_default()
+    }
+
+    fn write_balance(&mut self, owner: Address, amount: U256) {
+        self.balances.insert(owner, amount);
+    }
+
+    fn read_allowance(&self, owner: Address, spender: Address) -> U256 {
+        self.allowances
+            .get(&(owner, spender))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn write_allowance(&mut self, owner: Address, spender: Address, amount: U256) {
+        self.allowances.insert((owner, spender), amount);
+    }
+
+    fn read_total_supply(&self) -> U256 {
+        self.total_supply
+    }
+
+    fn write_total_supply(&mut self, total_supply: U256) {
+        self.total_supply = total_supply;
+    }
+
+    fn read_named_value<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
+        self.named_values.get(name).map(from_cl_value)
+    }
+
+    fn write_named_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T) {
+        self.named_values
+            .insert(name.to_string(), to_cl_value(value));
+    }
+
+    fn read_dictionary_value<T: CLTyped + FromBytes>(
+        &self,
+        dictionary_name: &str,
+        item_key: &str,
+    ) -> Option<T> {
+        self.dictionaries
+            .get(&(dictionary_name.to_string(), item_key.to_string()))
+            .map(from_cl_value)
+    }
+
+    fn write_dictionary_value<T: CLTyped + ToBytes>(
+        &mut self,
+        dictionary_name: &str,
+        item_key: &str,
+        value: T,
+    ) {
+        self.dictionaries.insert(
+            (dictionary_name.to_string(), item_key.to_string()),
+            to_cl_value(value),
+        );
+    }
+
+    fn caller(&self) -> Result<Address, Error> {
+        self.caller.ok_or(Error::InvalidContext)
+    }
+
+    fn block_time(&self) -> u64 {
+        self.block_time
+    }
+
+    fn contract_package_hash(&self) -> Result<ContractPackageHash, Error> {
+        self.contract_package_hash.ok_or(Error::InvalidContext)
+    }
+}
@@ -0,0 +1,83 @@
+//! Implementation of extended token metadata.
+use alloc::{string::String, vec::Vec};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
+
+use crate::{backend::Storage, constants::METADATA_KEY_NAME};
+
+/// Metadata of a token beyond its name, symbol and decimals, displayed by wallets and explorers.
+///
+/// Every field is optional, and the metadata of a newly installed token is empty unless
+/// configured otherwise.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct TokenMetadata {
+    /// Description of the token.
+    pub description: Option<String>,
+    /// URL of the icon of the token.
+    pub icon_url: Option<String>,
+    /// URL of the website of the project behind the token.
+    pub website: Option<String>,
+    /// Identifier of the chain the token is wrapped from.
+    pub origin_chain_id: Option<u64>,
+    /// Address of the contract of the wrapped token on its origin chain.
+    pub origin_contract: Option<String>,
+}
+
+impl CLTyped for TokenMetadata {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TokenMetadata {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.description.to_bytes()?);
+        result.append(&mut self.icon_url.to_bytes()?);
+        result.append(&mut self.website.to_bytes()?);
+        result.append(&mut self.origin_chain_id.to_bytes()?);
+        result.append(&mut self.origin_contract.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.description.serialized_length()
+            + self.icon_url.serialized_length()
+            + self.website.serialized_length()
+            + self.origin_chain_id.serialized_length()
+            + self.origin_contract.serialized_length()
+    }
+}
+
+impl FromBytes for TokenMetadata {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (description, remainder) = Option::<String>::from_bytes(bytes)?;
+        let (icon_url, remainder) = Option::<String>::from_bytes(remainder)?;
+        let (website, remainder) = Option::<String>::from_bytes(remainder)?;
+        let (origin_chain_id, remainder) = Option::<u64>::from_bytes(remainder)?;
+        let (origin_contract, remainder) = Option::<String>::from_bytes(remainder)?;
+        let metadata = TokenMetadata {
+            description,
+            icon_url,
+            website,
+            origin_chain_id,
+            origin_contract,
+        };
+        Ok((metadata, remainder))
+    }
+}
+
+/// Reads the extended metadata of a token.
+pub(crate) fn read_metadata_from<S: Storage>(storage: &S) -> TokenMetadata {
+    storage
+        .read_named_value(METADATA_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Writes the extended metadata of a token.
+pub(crate) fn write_metadata_to<S: Storage>(storage: &mut S, metadata: TokenMetadata) {
+    storage.write_named_value(METADATA_KEY_NAME, metadata);
+}
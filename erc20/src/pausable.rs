@@ -0,0 +1,15 @@
+//! Implementation of a pause flag.
+
+use crate::{backend::Storage, constants::PAUSED_KEY_NAME};
+
+/// Reads a pause flag.
+pub(crate) fn read_paused_from<S: Storage>(storage: &S) -> bool {
+    storage
+        .read_named_value(PAUSED_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Writes a pause flag.
+pub(crate) fn write_paused_to<S: Storage>(storage: &mut S, value: bool) {
+    storage.write_named_value(PAUSED_KEY_NAME, value);
+}
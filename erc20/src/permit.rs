@@ -0,0 +1,78 @@
+//! Implementation of signed permits.
+use alloc::{string::String, vec::Vec};
+
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    ContractPackageHash, U256,
+};
+
+use crate::{
+    backend::Storage,
+    balances,
+    constants::{CHAIN_NAME_KEY_NAME, NONCES_KEY_NAME},
+    Address,
+};
+
+/// Prefix which separates permit messages from any other data signed with the same key.
+const PERMIT_MESSAGE_PREFIX: &[u8] = b"casper-erc20-permit";
+
+/// Returns the message which the owner has to sign in order to permit `spender` to transfer up to
+/// `amount` of its tokens.
+///
+/// The message is domain-separated by the token's contract package hash and the chain name, so it
+/// can be used only once, with a single token, on a single network.
+pub fn permit_message(
+    contract_package_hash: ContractPackageHash,
+    chain_name: &str,
+    owner: Address,
+    spender: Address,
+    amount: U256,
+    nonce: u64,
+    deadline: u64,
+) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut message = Vec::from(PERMIT_MESSAGE_PREFIX);
+    message.append(&mut contract_package_hash.value().to_vec());
+    message.append(&mut chain_name.to_bytes()?);
+    message.append(&mut owner.to_bytes()?);
+    message.append(&mut spender.to_bytes()?);
+    message.append(&mut amount.to_bytes()?);
+    message.append(&mut nonce.to_bytes()?);
+    message.append(&mut deadline.to_bytes()?);
+    Ok(message)
+}
+
+/// Returns the message which the owner has to sign in order to permit a transfer of tokens
+/// identified by `token_id` among the tokens hosted by the same contract.
+///
+/// `message` is the [`permit_message`] of the permit, which is additionally domain-separated by
+/// the token id, so it cannot be replayed with another token of the contract.
+pub fn namespaced_permit_message(
+    mut message: Vec<u8>,
+    token_id: &str,
+) -> Result<Vec<u8>, bytesrepr::Error> {
+    message.append(&mut token_id.to_bytes()?);
+    Ok(message)
+}
+
+/// Writes the next permit nonce of `owner`.
+pub(crate) fn write_nonce_to<S: Storage>(storage: &mut S, owner: Address, nonce: u64) {
+    let dictionary_item_key = balances::make_dictionary_item_key(owner);
+    storage.write_dictionary_value(NONCES_KEY_NAME, &dictionary_item_key, nonce)
+}
+
+/// Reads the next permit nonce of `owner`.
+///
+/// If a given owner never used a permit, then a 0 is returned.
+pub(crate) fn read_nonce_from<S: Storage>(storage: &S, owner: Address) -> u64 {
+    let dictionary_item_key = balances::make_dictionary_item_key(owner);
+    storage
+        .read_dictionary_value(NONCES_KEY_NAME, &dictionary_item_key)
+        .unwrap_or_default()
+}
+
+/// Reads a chain name.
+pub(crate) fn read_chain_name_from<S: Storage>(storage: &S) -> String {
+    storage
+        .read_named_value(CHAIN_NAME_KEY_NAME)
+        .unwrap_or_default()
+}
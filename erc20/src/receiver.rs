@@ -0,0 +1,104 @@
+//! Implementation of callbacks notifying contracts about received tokens, approvals and flash
+//! loans.
+use casper_contract::contract_api::runtime;
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractPackageHash, RuntimeArgs, U256};
+
+use crate::{
+    constants::{
+        AMOUNT_RUNTIME_ARG_NAME, DATA_RUNTIME_ARG_NAME, FEE_RUNTIME_ARG_NAME,
+        INITIATOR_RUNTIME_ARG_NAME, ON_APPROVAL_RECEIVED_ENTRY_POINT_NAME,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME, ON_TOKEN_RECEIVED_ENTRY_POINT_NAME,
+        SENDER_RUNTIME_ARG_NAME,
+    },
+    error::Error,
+    Address,
+};
+
+/// Calls a well-known `entry_point` on the latest version of `contract_package_hash`.
+///
+/// Returns [`Error::CallbackRejected`] if the contract returns `false`.
+fn call_receiver(
+    contract_package_hash: ContractPackageHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) -> Result<(), Error> {
+    let accepted: bool =
+        runtime::call_versioned_contract(contract_package_hash, None, entry_point, args);
+    if !accepted {
+        return Err(Error::CallbackRejected);
+    }
+    Ok(())
+}
+
+/// Notifies `recipient` that it received `amount` of tokens from `sender`.
+///
+/// Accounts are not notified.
+pub(crate) fn notify_token_received(
+    recipient: Address,
+    sender: Address,
+    amount: U256,
+    data: Bytes,
+) -> Result<(), Error> {
+    match recipient {
+        Address::Account(_) => Ok(()),
+        Address::Contract(contract_package_hash) => call_receiver(
+            contract_package_hash,
+            ON_TOKEN_RECEIVED_ENTRY_POINT_NAME,
+            runtime_args! {
+                SENDER_RUNTIME_ARG_NAME => sender,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+                DATA_RUNTIME_ARG_NAME => data,
+            },
+        ),
+    }
+}
+
+/// Notifies `spender` that it has been allowed to transfer up to `amount` of `owner`'s tokens.
+///
+/// Accounts are not notified.
+pub(crate) fn notify_approval_received(
+    spender: Address,
+    owner: Address,
+    amount: U256,
+    data: Bytes,
+) -> Result<(), Error> {
+    match spender {
+        Address::Account(_) => Ok(()),
+        Address::Contract(contract_package_hash) => call_receiver(
+            contract_package_hash,
+            ON_APPROVAL_RECEIVED_ENTRY_POINT_NAME,
+            runtime_args! {
+                SENDER_RUNTIME_ARG_NAME => owner,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+                DATA_RUNTIME_ARG_NAME => data,
+            },
+        ),
+    }
+}
+
+/// Notifies `receiver` that it has been lent `amount` of tokens on behalf of `initiator`, to be
+/// paid back along with `fee` before the call returns.
+///
+/// Returns [`Error::InvalidReceiver`] if `receiver` is an account, as it cannot pay back the loan
+/// within the same deploy.
+pub(crate) fn notify_flash_loan(
+    receiver: Address,
+    initiator: Address,
+    amount: U256,
+    fee: U256,
+    data: Bytes,
+) -> Result<(), Error> {
+    match receiver {
+        Address::Account(_) => Err(Error::InvalidReceiver),
+        Address::Contract(contract_package_hash) => call_receiver(
+            contract_package_hash,
+            ON_FLASH_LOAN_ENTRY_POINT_NAME,
+            runtime_args! {
+                INITIATOR_RUNTIME_ARG_NAME => initiator,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+                FEE_RUNTIME_ARG_NAME => fee,
+                DATA_RUNTIME_ARG_NAME => data,
+            },
+        ),
+    }
+}
@@ -0,0 +1,132 @@
+//! Implementation of balance and total supply snapshots.
+//!
+//! Values are recorded lazily: the first time a balance or the total supply is about to change
+//! after a snapshot has been taken, its current value is recorded as a
+//! [checkpoint](crate::checkpoints) under the snapshot ID.
+use casper_types::U256;
+
+use crate::{
+    backend::Storage,
+    balances, checkpoints,
+    constants::{
+        BALANCE_SNAPSHOTS_KEY_NAME, SNAPSHOT_ID_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
+        TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+    },
+    Address,
+};
+
+/// Reads the ID of the most recent snapshot.
+///
+/// A 0 means no snapshot has been taken yet.
+pub(crate) fn read_snapshot_id_from<S: Storage>(storage: &S) -> u64 {
+    storage
+        .read_named_value(SNAPSHOT_ID_KEY_NAME)
+        .unwrap_or_default()
+}
+
+/// Writes the ID of the most recent snapshot.
+pub(crate) fn write_snapshot_id_to<S: Storage>(storage: &mut S, value: u64) {
+    storage.write_named_value(SNAPSHOT_ID_KEY_NAME, value);
+}
+
+/// Records `value` under `item_key` as of the snapshot with a specified ID, unless a value has
+/// already been recorded for it.
+fn record<S: Storage>(
+    storage: &mut S,
+    dictionary_name: &str,
+    item_key: &str,
+    snapshot_id: u64,
+    value: U256,
+) {
+    if snapshot_id == 0 {
+        return;
+    }
+    match checkpoints::read_last_checkpoint_from(storage, dictionary_name, item_key) {
+        Some((last_snapshot_id, _)) if last_snapshot_id >= snapshot_id => {}
+        _ => {
+            checkpoints::write_checkpoint_to(storage, dictionary_name, item_key, snapshot_id, value)
+        }
+    }
+}
+
+/// Looks up the value recorded under `item_key` as of the snapshot with a specified ID.
+///
+/// Returns `None` if the value has not changed since the snapshot was taken.
+fn lookup<S: Storage>(
+    storage: &S,
+    dictionary_name: &str,
+    item_key: &str,
+    snapshot_id: u64,
+) -> Option<U256> {
+    let index = checkpoints::partition_point_from(storage, dictionary_name, item_key, |id| {
+        id < snapshot_id
+    });
+    checkpoints::read_checkpoint_from(storage, dictionary_name, item_key, index)
+        .map(|(_, value)| value)
+}
+
+/// Records the `balance` of `address` as of the snapshot with a specified ID, if it has not been
+/// recorded yet.
+pub(crate) fn record_balance_snapshot_to<S: Storage>(
+    storage: &mut S,
+    snapshot_id: u64,
+    address: Address,
+    balance: U256,
+) {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    record(
+        storage,
+        BALANCE_SNAPSHOTS_KEY_NAME,
+        &dictionary_item_key,
+        snapshot_id,
+        balance,
+    );
+}
+
+/// Reads the balance of `address` as of the snapshot with a specified ID.
+///
+/// Returns `None` if the balance has not changed since the snapshot was taken.
+pub(crate) fn read_balance_snapshot_from<S: Storage>(
+    storage: &S,
+    snapshot_id: u64,
+    address: Address,
+) -> Option<U256> {
+    let dictionary_item_key = balances::make_dictionary_item_key(address);
+    lookup(
+        storage,
+        BALANCE_SNAPSHOTS_KEY_NAME,
+        &dictionary_item_key,
+        snapshot_id,
+    )
+}
+
+/// Records the `total_supply` as of the snapshot with a specified ID, if it has not been recorded
+/// yet.
+pub(crate) fn record_total_supply_snapshot_to<S: Storage>(
+    storage: &mut S,
+    snapshot_id: u64,
+    total_supply: U256,
+) {
+    record(
+        storage,
+        TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+        TOTAL_SUPPLY_KEY_NAME,
+        snapshot_id,
+        total_supply,
+    );
+}
+
+/// Reads the total supply as of the snapshot with a specified ID.
+///
+/// Returns `None` if the total supply has not changed since the snapshot was taken.
+pub(crate) fn read_total_supply_snapshot_from<S: Storage>(
+    storage: &S,
+    snapshot_id: u64,
+) -> Option<U256> {
+    lookup(
+        storage,
+        TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+        TOTAL_SUPPLY_KEY_NAME,
+        snapshot_id,
+    )
+}
@@ -0,0 +1,113 @@
+//! Implementation of vote delegation.
+//!
+//! Voting power of each delegatee is tracked as [checkpoints](crate::checkpoints) keyed by the
+//! block time, so past voting power can be looked up.
+use casper_types::U256;
+
+use crate::{
+    backend::Storage,
+    balances, checkpoints,
+    constants::{DELEGATES_KEY_NAME, VOTE_CHECKPOINTS_KEY_NAME},
+    error::Error,
+    Address,
+};
+
+/// Writes the delegatee of a specified account into a dictionary.
+pub(crate) fn write_delegate_to<S: Storage>(
+    storage: &mut S,
+    delegator: Address,
+    delegatee: Address,
+) {
+    let dictionary_item_key = balances::make_dictionary_item_key(delegator);
+    storage.write_dictionary_value(DELEGATES_KEY_NAME, &dictionary_item_key, delegatee);
+}
+
+/// Reads the delegatee of a specified account.
+///
+/// If a given account has not delegated its votes, then `None` is returned.
+pub(crate) fn read_delegate_from<S: Storage>(storage: &S, delegator: Address) -> Option<Address> {
+    let dictionary_item_key = balances::make_dictionary_item_key(delegator);
+
+    storage.read_dictionary_value(DELEGATES_KEY_NAME, &dictionary_item_key)
+}
+
+/// Reads the current voting power of a specified account.
+pub(crate) fn read_votes_from<S: Storage>(storage: &S, delegatee: Address) -> U256 {
+    let dictionary_item_key = balances::make_dictionary_item_key(delegatee);
+    checkpoints::read_last_checkpoint_from(storage, VOTE_CHECKPOINTS_KEY_NAME, &dictionary_item_key)
+        .map(|(_, votes)| votes)
+        .unwrap_or_default()
+}
+
+/// Reads the voting power of a specified account at the end of a given block time.
+pub(crate) fn read_past_votes_from<S: Storage>(
+    storage: &S,
+    delegatee: Address,
+    block_time: u64,
+) -> U256 {
+    let dictionary_item_key = balances::make_dictionary_item_key(delegatee);
+    let index = checkpoints::partition_point_from(
+        storage,
+        VOTE_CHECKPOINTS_KEY_NAME,
+        &dictionary_item_key,
+        |checkpoint_time| checkpoint_time <= block_time,
+    );
+    index
+        .checked_sub(1)
+        .and_then(|index| {
+            checkpoints::read_checkpoint_from(
+                storage,
+                VOTE_CHECKPOINTS_KEY_NAME,
+                &dictionary_item_key,
+                index,
+            )
+        })
+        .map(|(_, votes)| votes)
+        .unwrap_or_default()
+}
+
+/// Writes the voting power of a specified account as of a given block time.
+///
+/// Checkpoints written within the same block time are merged.
+fn write_votes_to<S: Storage>(storage: &mut S, delegatee: Address, block_time: u64, votes: U256) {
+    let dictionary_item_key = balances::make_dictionary_item_key(delegatee);
+    checkpoints::write_checkpoint_to(
+        storage,
+        VOTE_CHECKPOINTS_KEY_NAME,
+        &dictionary_item_key,
+        block_time,
+        votes,
+    );
+}
+
+/// Moves `amount` of voting power from the `source` delegatee to the `destination` delegatee.
+///
+/// `None` stands for an account which has not delegated its votes, or for minted and burned
+/// tokens.
+pub(crate) fn move_votes<S: Storage>(
+    storage: &mut S,
+    source: Option<Address>,
+    destination: Option<Address>,
+    amount: U256,
+    block_time: u64,
+) -> Result<(), Error> {
+    if source == destination || amount.is_zero() {
+        return Ok(());
+    }
+
+    if let Some(source) = source {
+        let new_votes = read_votes_from(storage, source)
+            .checked_sub(amount)
+            .ok_or(Error::Overflow)?;
+        write_votes_to(storage, source, block_time, new_votes);
+    }
+
+    if let Some(destination) = destination {
+        let new_votes = read_votes_from(storage, destination)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        write_votes_to(storage, destination, block_time, new_votes);
+    }
+
+    Ok(())
+}
@@ -3,6 +3,9 @@ mod test_fixture;
 
 #[cfg(test)]
 mod tests {
+    use casper_erc20::error::{
+        ERROR_INSUFFICIENT_ALLOWANCE, ERROR_INSUFFICIENT_BALANCE, ERROR_OVERFLOW,
+    };
     use casper_types::{Key, U256};
 
     use crate::test_fixture::{Sender, TestFixture};
@@ -96,7 +99,6 @@ mod tests {
         );
     }
 
-    #[should_panic(expected = "ApiError::User(65534) [131070]")]
     #[test]
     fn should_not_transfer_with_insufficient_balance() {
         let mut fixture = TestFixture::install_contract();
@@ -104,11 +106,13 @@ mod tests {
         let initial_ali_balance = fixture.balance_of(Key::from(fixture.ali)).unwrap();
         assert_eq!(fixture.balance_of(Key::from(fixture.bob)), None);
 
-        fixture.transfer(
-            Key::from(fixture.bob),
-            initial_ali_balance + U256::one(),
-            Sender(fixture.ali),
-        );
+        fixture.assert_reverts(ERROR_INSUFFICIENT_BALANCE, |fixture| {
+            fixture.transfer(
+                Key::from(fixture.bob),
+                initial_ali_balance + U256::one(),
+                Sender(fixture.ali),
+            )
+        });
     }
 
     #[test]
@@ -156,7 +160,6 @@ mod tests {
         );
     }
 
-    #[should_panic(expected = "ApiError::User(65533) [131069]")]
     #[test]
     fn should_not_transfer_from_more_than_approved() {
         let approve_amount = U256::from(100);
@@ -175,13 +178,62 @@ mod tests {
             Some(approve_amount)
         );
 
-        fixture.transfer_from(
-            Key::from(owner),
-            Key::from(recipient),
-            approve_amount + U256::one(),
-            Sender(spender),
+        fixture.assert_reverts(ERROR_INSUFFICIENT_ALLOWANCE, |fixture| {
+            fixture.transfer_from(
+                Key::from(owner),
+                Key::from(recipient),
+                approve_amount + U256::one(),
+                Sender(spender),
+            )
+        });
+    }
+
+    #[test]
+    fn should_increase_and_decrease_allowance() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+
+        fixture.approve(Key::from(spender), U256::from(100), Sender(owner));
+        fixture.increase_allowance(Key::from(spender), U256::from(42), Sender(owner));
+        assert_eq!(
+            fixture.allowance(Key::from(owner), Key::from(spender)),
+            Some(U256::from(142))
+        );
+
+        fixture.decrease_allowance(Key::from(spender), U256::from(100), Sender(owner));
+        assert_eq!(
+            fixture.allowance(Key::from(owner), Key::from(spender)),
+            Some(U256::from(42))
         );
     }
+
+    #[test]
+    fn should_not_decrease_allowance_below_zero() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+
+        fixture.approve(Key::from(spender), U256::from(100), Sender(owner));
+        fixture.assert_reverts(ERROR_INSUFFICIENT_ALLOWANCE, |fixture| {
+            fixture.decrease_allowance(Key::from(spender), U256::from(101), Sender(owner))
+        });
+    }
+
+    #[test]
+    fn should_not_increase_allowance_above_limits() {
+        let mut fixture = TestFixture::install_contract();
+
+        let owner = fixture.ali;
+        let spender = fixture.bob;
+
+        fixture.approve(Key::from(spender), U256::one(), Sender(owner));
+        fixture.assert_reverts(ERROR_OVERFLOW, |fixture| {
+            fixture.increase_allowance(Key::from(spender), U256::MAX, Sender(owner))
+        });
+    }
 }
 
 fn main() {
@@ -1,22 +1,21 @@
-use blake2::{
-    digest::{Update, VariableOutput},
-    VarBlake2b,
-};
+use std::panic::{self, AssertUnwindSafe};
+
 use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
-use casper_erc20::constants as consts;
+use casper_erc20::{client, constants as consts, Address};
 use casper_types::{
-    account::AccountHash,
-    bytesrepr::{FromBytes, ToBytes},
-    runtime_args, AsymmetricType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs, U256, U512,
+    account::AccountHash, bytesrepr::FromBytes, runtime_args, ApiError, AsymmetricType, CLTyped,
+    ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs, U256, U512,
 };
 
 const CONTRACT_ERC20_TOKEN: &str = "erc20_token.wasm";
-const CONTRACT_KEY_NAME: &str = "erc20_token_contract";
+const CONTRACT_KEY_NAME: &str = client::ERC20_TOKEN_CONTRACT_KEY_NAME;
 
-fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
-    let mut hasher = VarBlake2b::new(32).unwrap();
-    hasher.update(item_key_string);
-    hasher.finalize_boxed()
+fn to_address(key: Key) -> Address {
+    match key {
+        Key::Account(account_hash) => Address::from(account_hash),
+        Key::Hash(hash) => Address::from(ContractPackageHash::new(hash)),
+        _ => panic!("{} is not an account or a contract package", key),
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -109,6 +108,25 @@ impl TestFixture {
         self.context.run(session);
     }
 
+    /// Asserts that `call` reverts with the library error whose code is `error_code`, one of the
+    /// `casper_erc20::error::ERROR_*` constants.
+    pub fn assert_reverts<F: FnOnce(&mut TestFixture)>(&mut self, error_code: u16, call: F) {
+        let payload =
+            panic::catch_unwind(AssertUnwindSafe(|| call(self))).expect_err("should revert");
+        let message = payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| payload.downcast_ref::<&str>().copied())
+            .unwrap_or_default();
+        let expected_error = format!("{:?}", ApiError::User(error_code));
+        assert!(
+            message.contains(&expected_error),
+            "should revert with {}, but {}",
+            expected_error,
+            message
+        );
+    }
+
     pub fn token_name(&self) -> String {
         self.query_contract(consts::NAME_RUNTIME_ARG_NAME).unwrap()
     }
@@ -124,23 +142,19 @@ impl TestFixture {
     }
 
     pub fn balance_of(&self, account: Key) -> Option<U256> {
-        let item_key = base64::encode(&account.to_bytes().unwrap());
+        let item_key = client::balance_key(to_address(account));
 
         let key = Key::Hash(self.contract_hash().value());
         let value = self
             .context
-            .query_dictionary_item(key, Some(consts::BALANCES_KEY_NAME.to_string()), item_key)
+            .query_dictionary_item(key, Some(client::BALANCES_KEY_NAME.to_string()), item_key)
             .ok()?;
 
-        Some(value.into_t::<U256>().unwrap())
+        Some(client::decode_amount(value).unwrap())
     }
 
     pub fn allowance(&self, owner: Key, spender: Key) -> Option<U256> {
-        let mut preimage = Vec::new();
-        preimage.append(&mut owner.to_bytes().unwrap());
-        preimage.append(&mut spender.to_bytes().unwrap());
-        let key_bytes = blake2b256(&preimage);
-        let allowance_item_key = hex::encode(&key_bytes);
+        let allowance_item_key = client::allowance_key(to_address(owner), to_address(spender));
 
         let key = Key::Hash(self.contract_hash().value());
 
@@ -148,12 +162,12 @@ impl TestFixture {
             .context
             .query_dictionary_item(
                 key,
-                Some(consts::ALLOWANCES_KEY_NAME.to_string()),
+                Some(client::ALLOWANCES_KEY_NAME.to_string()),
                 allowance_item_key,
             )
             .ok()?;
 
-        Some(value.into_t::<U256>().unwrap())
+        Some(client::decode_amount(value).unwrap())
     }
 
     pub fn transfer(&mut self, recipient: Key, amount: U256, sender: Sender) {
@@ -178,6 +192,28 @@ impl TestFixture {
         );
     }
 
+    pub fn increase_allowance(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            consts::INCREASE_ALLOWANCE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::SPENDER_RUNTIME_ARG_NAME => spender,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn decrease_allowance(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            consts::DECREASE_ALLOWANCE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::SPENDER_RUNTIME_ARG_NAME => spender,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
     pub fn transfer_from(&mut self, owner: Key, recipient: Key, amount: U256, sender: Sender) {
         self.call(
             sender,
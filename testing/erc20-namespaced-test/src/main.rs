@@ -0,0 +1,59 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_erc20::{
+    constants::{
+        ADMIN_ROLE, DECIMALS_RUNTIME_ARG_NAME, NAME_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME,
+        TOKEN_ID_RUNTIME_ARG_NAME, TOTAL_SUPPLY_RUNTIME_ARG_NAME,
+    },
+    entry_points, ERC20Builder, ERC20,
+};
+use casper_types::U256;
+
+const TEST_CONTRACT_KEY_NAME: &str = "namespaced_test_contract";
+const FIRST_TOKEN_ID: &str = "first";
+const TOKEN_NAME: &str = "CasperTest";
+const TOKEN_SYMBOL: &str = "CSPRT";
+const TOKEN_DECIMALS: u8 = 8;
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const TOKEN_CHAIN_NAME: &str = "casper-test";
+
+casper_erc20::erc20_entry_points!(namespaced);
+
+#[no_mangle]
+pub extern "C" fn create_token() {
+    // Only the administrators of the first token are allowed to create further tokens.
+    ERC20::namespaced(FIRST_TOKEN_ID)
+        .ensure_role(ADMIN_ROLE)
+        .unwrap_or_revert();
+
+    let token_id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    let decimals = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let total_supply = runtime::get_named_arg(TOTAL_SUPPLY_RUNTIME_ARG_NAME);
+
+    ERC20::create_token(&token_id, name, symbol, decimals, total_supply).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    let _token = ERC20Builder::new(
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+    )
+    .with_initial_supply(U256::from(TOKEN_TOTAL_SUPPLY))
+    .with_chain_name(TOKEN_CHAIN_NAME)
+    .with_contract_key_name(TEST_CONTRACT_KEY_NAME)
+    .with_token_id(FIRST_TOKEN_ID)
+    .upgradeable()
+    .with_entry_point(entry_points::create_token())
+    .install()
+    .unwrap_or_revert();
+}
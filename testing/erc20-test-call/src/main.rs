@@ -11,30 +11,48 @@ use alloc::{
 use casper_contract::{
     self,
     contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_erc20::{
     constants::{
-        AMOUNT_RUNTIME_ARG_NAME, APPROVE_ENTRY_POINT_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        AMOUNT_RUNTIME_ARG_NAME, APPROVE_ENTRY_POINT_NAME, APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME,
+        DATA_RUNTIME_ARG_NAME, EXPIRES_AT_RUNTIME_ARG_NAME, FEE_RUNTIME_ARG_NAME,
+        FLASH_LOAN_ENTRY_POINT_NAME, RECEIVER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
         TRANSFER_ENTRY_POINT_NAME, TRANSFER_FROM_ENTRY_POINT_NAME,
     },
     Address,
 };
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args,
+    system::CallStackElement,
+    ApiError, CLTyped, CLValue, ContractHash, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, RuntimeArgs, U256,
 };
 
 const CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_total_supply";
 const CHECK_BALANCE_OF_ENTRY_POINT_NAME: &str = "check_balance_of";
 const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_contract";
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
+const APPROVE_WITH_EXPIRY_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str =
+    "approve_with_expiry_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "check_total_supply_at";
+const CHECK_GET_VOTES_ENTRY_POINT_NAME: &str = "check_get_votes";
+const CHECK_GET_PAST_VOTES_ENTRY_POINT_NAME: &str = "check_get_past_votes";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+const BLOCK_TIME_RUNTIME_ARG_NAME: &str = "block_time";
 const RESULT_KEY: &str = "result";
+/// Callback data which makes this contract reject received tokens and approvals.
+const REJECT_DATA: &[u8] = b"reject";
+const STANDING_APPROVAL_DATA: &[u8] = b"standing_approval";
+const NESTED_FLASH_LOAN_DATA: &[u8] = b"nested_flash_loan";
 const ERC20_TEST_CALL_KEY: &str = "erc20_test_call";
 
 fn store_result<T: CLTyped + ToBytes>(result: T) {
@@ -95,6 +113,78 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_balance_of_at() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let balance_args = runtime_args! {
+        casper_erc20::constants::ADDRESS_RUNTIME_ARG_NAME => address,
+        casper_erc20::constants::SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::BALANCE_OF_AT_ENTRY_POINT_NAME,
+        balance_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_total_supply_at() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let total_supply_args = runtime_args! {
+        casper_erc20::constants::SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        total_supply_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_get_votes() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+
+    let votes_args = runtime_args! {
+        casper_erc20::constants::ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::GET_VOTES_ENTRY_POINT_NAME,
+        votes_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_get_past_votes() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let block_time: u64 = runtime::get_named_arg(BLOCK_TIME_RUNTIME_ARG_NAME);
+
+    let past_votes_args = runtime_args! {
+        casper_erc20::constants::ADDRESS_RUNTIME_ARG_NAME => address,
+        casper_erc20::constants::BLOCK_TIME_RUNTIME_ARG_NAME => block_time,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        casper_erc20::constants::GET_PAST_VOTES_ENTRY_POINT_NAME,
+        past_votes_args,
+    );
+
+    store_result(result);
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
@@ -143,6 +233,95 @@ extern "C" fn approve_as_stored_contract() {
     runtime::call_contract::<()>(token_contract, APPROVE_ENTRY_POINT_NAME, approve_args);
 }
 
+#[no_mangle]
+extern "C" fn approve_with_expiry_as_stored_contract() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let expires_at: u64 = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
+
+    let approve_args = runtime_args! {
+        SPENDER_RUNTIME_ARG_NAME => spender,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+        EXPIRES_AT_RUNTIME_ARG_NAME => expires_at,
+    };
+
+    runtime::call_contract::<()>(
+        token_contract,
+        APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME,
+        approve_args,
+    );
+}
+
+/// Stores the received amount, and accepts it unless asked to reject it.
+fn receive(amount: U256, data: Bytes) -> bool {
+    if data.as_slice() == REJECT_DATA {
+        return false;
+    }
+    store_result(amount);
+    true
+}
+
+#[no_mangle]
+extern "C" fn on_token_received() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    let accepted = receive(amount, data);
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
+#[no_mangle]
+extern "C" fn on_approval_received() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    let accepted = receive(amount, data);
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
+/// Approves the lending token to take the loan back along with the fee, unless asked to rely on
+/// a standing approval, and accepts the loan unless asked to reject it.
+///
+/// Asks for another loan of the same amount before repaying this one if asked to nest loans.
+#[no_mangle]
+extern "C" fn on_flash_loan() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let fee: U256 = runtime::get_named_arg(FEE_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    let (token_package, token_contract) = match runtime::get_call_stack().into_iter().rev().nth(1) {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            contract_hash,
+        }) => (contract_package_hash, contract_hash),
+        _ => runtime::revert(ApiError::InvalidCallerInfoRequest),
+    };
+    if data.as_slice() == NESTED_FLASH_LOAN_DATA {
+        let own_package = match runtime::get_call_stack().last() {
+            Some(CallStackElement::StoredContract {
+                contract_package_hash,
+                ..
+            }) => *contract_package_hash,
+            _ => runtime::revert(ApiError::InvalidCallerInfoRequest),
+        };
+        let flash_loan_args = runtime_args! {
+            RECEIVER_RUNTIME_ARG_NAME => Address::Contract(own_package),
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+            DATA_RUNTIME_ARG_NAME => Bytes::new(),
+        };
+        runtime::call_contract::<()>(token_contract, FLASH_LOAN_ENTRY_POINT_NAME, flash_loan_args);
+    }
+    if data.as_slice() != STANDING_APPROVAL_DATA {
+        let approve_args = runtime_args! {
+            SPENDER_RUNTIME_ARG_NAME => Address::Contract(token_package),
+            AMOUNT_RUNTIME_ARG_NAME => amount + fee,
+        };
+        runtime::call_contract::<()>(token_contract, APPROVE_ENTRY_POINT_NAME, approve_args);
+    }
+
+    let accepted = receive(amount, data);
+    runtime::ret(CLValue::from_t(accepted).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
     let mut entry_points = EntryPoints::new();
@@ -177,6 +356,48 @@ pub extern "C" fn call() {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+    let check_balance_of_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_total_supply_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_get_votes_entrypoint = EntryPoint::new(
+        String::from(CHECK_GET_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    let check_get_past_votes_entrypoint = EntryPoint::new(
+        String::from(CHECK_GET_PAST_VOTES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(BLOCK_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
 
     let transfer_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
@@ -202,6 +423,19 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let approve_with_expiry_as_stored_contract_entrypoint = EntryPoint::new(
+        String::from(APPROVE_WITH_EXPIRY_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(EXPIRES_AT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     let transfer_from_as_stored_contract_entrypoint = EntryPoint::new(
         String::from(TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME),
         vec![
@@ -218,9 +452,17 @@ pub extern "C" fn call() {
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
+    entry_points.add_entry_point(check_balance_of_at_entrypoint);
+    entry_points.add_entry_point(check_total_supply_at_entrypoint);
+    entry_points.add_entry_point(check_get_votes_entrypoint);
+    entry_points.add_entry_point(check_get_past_votes_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(approve_with_expiry_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(casper_erc20::entry_points::on_token_received());
+    entry_points.add_entry_point(casper_erc20::entry_points::on_approval_received());
+    entry_points.add_entry_point(casper_erc20::entry_points::on_flash_loan());
 
     let (_contract_hash, _version) = storage::new_contract(
         entry_points,
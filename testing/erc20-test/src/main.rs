@@ -5,23 +5,31 @@ extern crate alloc;
 
 use alloc::{
     string::{String, ToString},
-    vec,
+    vec::Vec,
 };
 use core::ops::{Deref, DerefMut};
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_erc20::{
-    constants::{ADDRESS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME},
-    Address, Error, ERC20,
+    constants::{
+        ACCOUNT_RUNTIME_ARG_NAME, ADDRESS_RUNTIME_ARG_NAME, AMOUNTS_RUNTIME_ARG_NAME,
+        AMOUNT_RUNTIME_ARG_NAME, BASIS_POINTS_RUNTIME_ARG_NAME, BLOCK_TIME_RUNTIME_ARG_NAME,
+        CAP_RUNTIME_ARG_NAME, DATA_RUNTIME_ARG_NAME, DEADLINE_RUNTIME_ARG_NAME,
+        DELEGATEE_RUNTIME_ARG_NAME, EXPIRES_AT_RUNTIME_ARG_NAME, LOG_INDEX_RUNTIME_ARG_NAME,
+        METADATA_RUNTIME_ARG_NAME, MINTER_ROLE, NAME_RUNTIME_ARG_NAME,
+        ORIGIN_CHAIN_ID_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, PUBLIC_KEY_RUNTIME_ARG_NAME,
+        RECEIVER_RUNTIME_ARG_NAME, RECIPIENTS_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        ROLE_RUNTIME_ARG_NAME, SIGNATURE_RUNTIME_ARG_NAME, SNAPSHOT_ID_RUNTIME_ARG_NAME,
+        SPENDER_RUNTIME_ARG_NAME, SYMBOL_RUNTIME_ARG_NAME, TO_CHAIN_ID_RUNTIME_ARG_NAME,
+        TX_HASH_RUNTIME_ARG_NAME,
+    },
+    Address, ERC20Builder, Error, TokenMetadata, ERC20,
 };
 use casper_types::{
-    account::AccountHash, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+    account::AccountHash, bytesrepr::Bytes, CLValue, ContractPackageHash, EntryPoints, PublicKey,
+    U256,
 };
 
-const MINT_ENTRY_POINT_NAME: &str = "mint";
-const BURN_ENTRY_POINT_NAME: &str = "burn";
-
 /// "erc20" is not mentioned here intentionally as the functionality is not compatible with ERC20
 /// token standard.
 const TEST_CONTRACT_KEY_NAME: &str = "test_contract";
@@ -29,6 +37,9 @@ const TOKEN_NAME: &str = "CasperTest";
 const TOKEN_SYMBOL: &str = "CSPRT";
 const TOKEN_DECIMALS: u8 = 8;
 const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const TOKEN_CHAIN_NAME: &str = "casper-test";
+const TOKEN_CAP: u64 = 10_000_000_000;
+const TOKEN_DESCRIPTION: &str = "Token used to test the casper-erc20 library";
 
 const TOKEN_OWNER_ADDRESS_1: Address = Address::Account(AccountHash::new([42; 32]));
 const TOKEN_OWNER_AMOUNT_1: u64 = 1_000_000;
@@ -49,50 +60,60 @@ impl TestToken {
 
         let mut entry_points = EntryPoints::new();
 
-        let mint_entrypoint = EntryPoint::new(
-            MINT_ENTRY_POINT_NAME,
-            vec![
-                Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
-                Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
-            ],
-            CLType::Unit,
-            // NOTE: For security reasons never use this entrypoint definition in a production
-            // contract. This is marks the entry point as public.
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        let burn_entrypoint = EntryPoint::new(
-            BURN_ENTRY_POINT_NAME,
-            vec![
-                Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
-                Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
-            ],
-            CLType::Unit,
-            // NOTE: For security reasons never use this entrypoint definition in a production
-            // contract. This is marks the entry point as public.
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-
         entry_points.add_entry_point(casper_erc20::entry_points::total_supply());
         entry_points.add_entry_point(casper_erc20::entry_points::balance_of());
-        entry_points.add_entry_point(mint_entrypoint);
-        entry_points.add_entry_point(burn_entrypoint);
+        // Minting and burning is authorized on-chain by the minter role.
+        entry_points.add_entry_point(casper_erc20::entry_points::mint());
+        entry_points.add_entry_point(casper_erc20::entry_points::burn());
+        entry_points.add_entry_point(casper_erc20::entry_points::bridge_mint());
+        entry_points.add_entry_point(casper_erc20::entry_points::request_bridge_back());
+        entry_points.add_entry_point(casper_erc20::entry_points::allowance());
+        entry_points.add_entry_point(casper_erc20::entry_points::permit());
+        entry_points.add_entry_point(casper_erc20::entry_points::nonce_of());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::freeze());
+        entry_points.add_entry_point(casper_erc20::entry_points::unfreeze());
+        entry_points.add_entry_point(casper_erc20::entry_points::is_frozen());
+        entry_points.add_entry_point(casper_erc20::entry_points::snapshot());
+        entry_points.add_entry_point(casper_erc20::entry_points::balance_of_at());
+        entry_points.add_entry_point(casper_erc20::entry_points::total_supply_at());
+        entry_points.add_entry_point(casper_erc20::entry_points::delegate());
+        entry_points.add_entry_point(casper_erc20::entry_points::delegates());
+        entry_points.add_entry_point(casper_erc20::entry_points::get_votes());
+        entry_points.add_entry_point(casper_erc20::entry_points::get_past_votes());
+        entry_points.add_entry_point(casper_erc20::entry_points::transfer_and_call());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve_and_call());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve());
+        entry_points.add_entry_point(casper_erc20::entry_points::approve_with_expiry());
+        entry_points.add_entry_point(casper_erc20::entry_points::batch_transfer());
+        entry_points.add_entry_point(casper_erc20::entry_points::batch_transfer_from());
+        entry_points.add_entry_point(casper_erc20::entry_points::flash_loan());
+        entry_points.add_entry_point(casper_erc20::entry_points::max_flash_loan());
+        entry_points.add_entry_point(casper_erc20::entry_points::flash_fee());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_flash_fee());
+        entry_points.add_entry_point(casper_erc20::entry_points::set_flash_fee_recipient());
 
-        // Caution: This test uses `install_custom` without providing default entrypoints as
-        // described by ERC20 token standard.
+        // Caution: This test replaces the default entrypoints described by ERC20 token standard.
         //
         // This is unsafe and this test contract is not a ERC20 token standard-compliant token.
         // Contract developers should use example/erc20 contract instead as a template for writing
         // their own tokens.
-        let erc20 = ERC20::install_custom(
-            name,
-            symbol,
-            decimals,
-            total_supply,
-            TEST_CONTRACT_KEY_NAME,
-            entry_points,
-        )?;
+        let erc20 = ERC20Builder::new(name, symbol, decimals)
+            .with_initial_supply(total_supply)
+            .with_initial_holder(TOKEN_OWNER_ADDRESS_1, U256::from(TOKEN_OWNER_AMOUNT_1))
+            .with_initial_holder(TOKEN_OWNER_ADDRESS_2, U256::from(TOKEN_OWNER_AMOUNT_2))
+            .with_chain_name(TOKEN_CHAIN_NAME)
+            .with_contract_key_name(TEST_CONTRACT_KEY_NAME)
+            .with_entry_points(entry_points)
+            .upgradeable()
+            .with_cap(U256::from(TOKEN_CAP))
+            .pausable()
+            .with_roles()
+            .with_metadata(TokenMetadata {
+                description: Some(TOKEN_DESCRIPTION.to_string()),
+                ..TokenMetadata::default()
+            })
+            .install()?;
         Ok(TestToken { erc20 })
     }
 }
@@ -111,42 +132,297 @@ impl DerefMut for TestToken {
     }
 }
 
+casper_erc20::erc20_entry_points!(
+    TestToken,
+    mint_burn_authorization = |token: &TestToken| token.ensure_role(MINTER_ROLE).map(|_| ())
+);
+
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .grant_role(&role, account)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .revoke_role(&role, account)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn renounce_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    TestToken::default().renounce_role(&role).unwrap_or_revert();
+}
+
 #[no_mangle]
-pub extern "C" fn total_supply() {
-    let val = TestToken::default().total_supply();
+pub extern "C" fn has_role() {
+    let role: String = runtime::get_named_arg(ROLE_RUNTIME_ARG_NAME);
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let val = TestToken::default().has_role(&role, account);
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
 #[no_mangle]
-pub extern "C" fn balance_of() {
-    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
-    let val = TestToken::default().balance_of(address);
+pub extern "C" fn bridge_mint() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let origin_chain_id: u64 = runtime::get_named_arg(ORIGIN_CHAIN_ID_RUNTIME_ARG_NAME);
+    let tx_hash: String = runtime::get_named_arg(TX_HASH_RUNTIME_ARG_NAME);
+    let log_index: u64 = runtime::get_named_arg(LOG_INDEX_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .bridge_mint(recipient, amount, origin_chain_id, &tx_hash, log_index)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn request_bridge_back() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let to_chain_id: u64 = runtime::get_named_arg(TO_CHAIN_ID_RUNTIME_ARG_NAME);
+    let receiver: Bytes = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
+    let index = TestToken::default()
+        .request_bridge_back(amount, to_chain_id, receiver)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(index).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn pause() {
+    TestToken::default().pause().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    TestToken::default().unpause().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn is_paused() {
+    let val = TestToken::default().is_paused();
     runtime::ret(CLValue::from_t(val).unwrap_or_revert());
 }
 
 #[no_mangle]
-pub extern "C" fn mint() {
+pub extern "C" fn permit() {
+    let public_key: PublicKey = runtime::get_named_arg(PUBLIC_KEY_RUNTIME_ARG_NAME);
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let deadline: u64 = runtime::get_named_arg(DEADLINE_RUNTIME_ARG_NAME);
+    let signature: Bytes = runtime::get_named_arg(SIGNATURE_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .permit(public_key, spender, amount, deadline, signature)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn nonce_of() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let val = TestToken::default().nonce_of(owner);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn cap() {
+    let val = TestToken::default().cap();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn lower_cap() {
+    let cap: U256 = runtime::get_named_arg(CAP_RUNTIME_ARG_NAME);
+    TestToken::default().lower_cap(cap).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn freeze() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default().freeze(address).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn unfreeze() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    TestToken::default().unfreeze(address).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn is_frozen() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().is_frozen(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn snapshot() {
+    let snapshot_id = TestToken::default().snapshot().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(snapshot_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .balance_of_at(address, snapshot_id)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply_at() {
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .total_supply_at(snapshot_id)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn delegate() {
+    let delegatee: Address = runtime::get_named_arg(DELEGATEE_RUNTIME_ARG_NAME);
+    TestToken::default().delegate(delegatee).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn delegates() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().delegates(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_votes() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let val = TestToken::default().get_votes(address);
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_past_votes() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let block_time: u64 = runtime::get_named_arg(BLOCK_TIME_RUNTIME_ARG_NAME);
+    let val = TestToken::default()
+        .get_past_votes(address, block_time)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_and_call() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
-    TestToken::default().mint(owner, amount).unwrap_or_revert();
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .transfer_and_call(recipient, amount, data)
+        .unwrap_or_revert();
 }
 
 #[no_mangle]
-pub extern "C" fn burn() {
+pub extern "C" fn approve_and_call() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .approve_and_call(spender, amount, data)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn approve_with_expiry() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let expires_at: u64 = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .approve_with_expiry(spender, amount, expires_at)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_transfer() {
+    let recipients: Vec<Address> = runtime::get_named_arg(RECIPIENTS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .batch_transfer(recipients, amounts)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_transfer_from() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipients: Vec<Address> = runtime::get_named_arg(RECIPIENTS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .batch_transfer_from(owner, recipients, amounts)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn flash_loan() {
+    let receiver: Address = runtime::get_named_arg(RECEIVER_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
-    TestToken::default().burn(owner, amount).unwrap_or_revert();
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .flash_loan(receiver, amount, data)
+        .unwrap_or_revert();
 }
 
 #[no_mangle]
-fn call() {
-    let mut test_token = TestToken::install().unwrap_or_revert();
+pub extern "C" fn max_flash_loan() {
+    let val = TestToken::default().max_flash_loan();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn flash_fee() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let val = TestToken::default().flash_fee(amount).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_flash_fee() {
+    let basis_points: u32 = runtime::get_named_arg(BASIS_POINTS_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_flash_fee(basis_points)
+        .unwrap_or_revert();
+}
 
-    test_token
-        .mint(TOKEN_OWNER_ADDRESS_1, U256::from(TOKEN_OWNER_AMOUNT_1))
+#[no_mangle]
+pub extern "C" fn set_flash_fee_recipient() {
+    let recipient: Option<Address> = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_flash_fee_recipient(recipient)
         .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn metadata() {
+    let val = TestToken::default().metadata();
+    runtime::ret(CLValue::from_t(val).unwrap_or_revert());
+}
 
-    test_token
-        .mint(TOKEN_OWNER_ADDRESS_2, U256::from(TOKEN_OWNER_AMOUNT_2))
+#[no_mangle]
+pub extern "C" fn set_metadata() {
+    let metadata: TokenMetadata = runtime::get_named_arg(METADATA_RUNTIME_ARG_NAME);
+    TestToken::default()
+        .set_metadata(metadata)
         .unwrap_or_revert();
 }
+
+#[no_mangle]
+pub extern "C" fn rename() {
+    let name: String = runtime::get_named_arg(NAME_RUNTIME_ARG_NAME);
+    let symbol: String = runtime::get_named_arg(SYMBOL_RUNTIME_ARG_NAME);
+    TestToken::default().rename(name, symbol).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn call() {
+    let _test_token = TestToken::install().unwrap_or_revert();
+}
@@ -0,0 +1,88 @@
+use casper_erc20::{Address, AddressError};
+use casper_types::{account::AccountHash, ContractPackageHash, PublicKey, SecretKey};
+
+const ACCOUNT: Address = Address::Account(AccountHash::new([42; 32]));
+const CONTRACT: Address = Address::Contract(ContractPackageHash::new([42; 32]));
+
+#[test]
+fn should_round_trip_formatted_addresses() {
+    let hash = "2a".repeat(32);
+
+    let account_string = ACCOUNT.to_string();
+    assert_eq!(account_string, format!("account-hash-{}", hash));
+    assert!(matches!(account_string.parse::<Address>(), Ok(address) if address == ACCOUNT));
+
+    let contract_string = CONTRACT.to_string();
+    assert_eq!(contract_string, format!("contract-package-wasm{}", hash));
+    assert!(matches!(contract_string.parse::<Address>(), Ok(address) if address == CONTRACT));
+}
+
+#[test]
+fn should_not_parse_malformed_addresses() {
+    assert!(matches!(
+        "uref-2a".parse::<Address>(),
+        Err(AddressError::InvalidPrefix)
+    ));
+    assert!(matches!(
+        "account-hash-2a".parse::<Address>(),
+        Err(AddressError::InvalidAccountHash(_))
+    ));
+    assert!(matches!(
+        "contract-package-wasmzz".parse::<Address>(),
+        Err(AddressError::InvalidContractPackageHash(_))
+    ));
+}
+
+#[test]
+fn should_round_trip_hex_addresses() {
+    for address in &[ACCOUNT, CONTRACT] {
+        let hex = address
+            .to_hex()
+            .unwrap_or_else(|_| panic!("should encode address"));
+        assert!(matches!(Address::from_hex(&hex), Ok(decoded) if decoded == *address));
+    }
+
+    assert!(matches!(
+        Address::from_hex("not hex"),
+        Err(AddressError::InvalidHex)
+    ));
+    assert!(matches!(
+        Address::from_hex("2a2a"),
+        Err(AddressError::InvalidBytes(_))
+    ));
+}
+
+#[test]
+fn should_convert_public_key_to_account_address() {
+    let secret_key = SecretKey::ed25519_from_bytes([7; 32]).expect("should create secret key");
+    let public_key = PublicKey::from(&secret_key);
+
+    assert_eq!(
+        Address::from(&public_key),
+        Address::Account(public_key.to_account_hash())
+    );
+}
+
+#[test]
+fn should_round_trip_json_addresses() {
+    for address in &[ACCOUNT, CONTRACT] {
+        let json = serde_json::to_string(address).expect("should serialize address");
+        assert_eq!(json, format!("\"{}\"", address));
+
+        let deserialized: Address =
+            serde_json::from_str(&json).expect("should deserialize address");
+        assert_eq!(deserialized, *address);
+    }
+
+    assert!(serde_json::from_str::<Address>("\"uref-2a\"").is_err());
+    assert!(serde_json::from_str::<Address>("42").is_err());
+}
+
+#[test]
+fn should_describe_address_as_json_string() {
+    let schema =
+        serde_json::to_value(schemars::schema_for!(Address)).expect("should serialize schema");
+
+    assert_eq!(schema["title"], "Address");
+    assert_eq!(schema["type"], "string");
+}
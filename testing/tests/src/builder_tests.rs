@@ -0,0 +1,130 @@
+use casper_erc20::{
+    constants::{BALANCES_KEY_NAME, TOKEN_IDS_KEY_NAME},
+    entry_points, Address, ERC20Builder, Error,
+};
+use casper_types::{account::AccountHash, AccessRights, Key, URef, U256};
+
+const TOKEN_NAME: &str = "CasperTest";
+const TOKEN_SYMBOL: &str = "CSPRT";
+const TOKEN_DECIMALS: u8 = 100;
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const TOKEN_HOLDER_AMOUNT: u64 = 1_000_000;
+const TOKEN_CHAIN_NAME: &str = "casper-test";
+
+const TOKEN_ID: &str = "first";
+
+const HOLDER: Address = Address::Account(AccountHash::new([2; 32]));
+
+fn builder() -> ERC20Builder {
+    ERC20Builder::new(
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+    )
+    .with_initial_supply(U256::from(TOKEN_TOTAL_SUPPLY))
+    .with_initial_holder(HOLDER, U256::from(TOKEN_HOLDER_AMOUNT))
+    .with_chain_name(TOKEN_CHAIN_NAME)
+}
+
+#[test]
+fn should_validate_builder() {
+    let total_initial_supply = U256::from(TOKEN_TOTAL_SUPPLY) + U256::from(TOKEN_HOLDER_AMOUNT);
+
+    let builder = builder()
+        .with_cap(total_initial_supply)
+        .with_named_key("extra", Key::URef(URef::new([1; 32], AccessRights::READ)));
+
+    assert!(builder.validate().is_ok());
+    assert!(matches!(
+        builder.total_initial_supply(),
+        Ok(total) if total == total_initial_supply
+    ));
+}
+
+#[test]
+fn should_not_validate_builder_with_empty_names() {
+    let builder = ERC20Builder::new(String::new(), TOKEN_SYMBOL.to_string(), TOKEN_DECIMALS);
+    assert!(matches!(
+        builder.validate(),
+        Err(Error::InvalidConfiguration)
+    ));
+
+    let builder = ERC20Builder::new(TOKEN_NAME.to_string(), String::new(), TOKEN_DECIMALS);
+    assert!(matches!(
+        builder.validate(),
+        Err(Error::InvalidConfiguration)
+    ));
+
+    let builder = builder().with_contract_key_name("");
+    assert!(matches!(
+        builder.validate(),
+        Err(Error::InvalidConfiguration)
+    ));
+}
+
+#[test]
+fn should_not_validate_builder_replacing_token_named_keys() {
+    let builder = builder().with_named_key(
+        BALANCES_KEY_NAME,
+        Key::URef(URef::new([1; 32], AccessRights::READ)),
+    );
+    assert!(matches!(
+        builder.validate(),
+        Err(Error::InvalidConfiguration)
+    ));
+}
+
+#[test]
+fn should_validate_namespaced_builder() {
+    let extra_key = Key::URef(URef::new([1; 32], AccessRights::READ));
+
+    // Unprefixed names are free to use once the state of the token is namespaced.
+    let builder = builder()
+        .with_token_id(TOKEN_ID)
+        .with_named_key(BALANCES_KEY_NAME, extra_key);
+    assert!(builder.validate().is_ok());
+
+    for name in &["first_balances", TOKEN_IDS_KEY_NAME] {
+        let builder = builder()
+            .with_token_id(TOKEN_ID)
+            .with_named_key(name, extra_key);
+        assert!(matches!(
+            builder.validate(),
+            Err(Error::InvalidConfiguration)
+        ));
+    }
+
+    for token_id in &["", "first_chain"] {
+        let builder = builder().with_token_id(token_id);
+        assert!(matches!(
+            builder.validate(),
+            Err(Error::InvalidConfiguration)
+        ));
+    }
+}
+
+#[test]
+fn should_not_validate_builder_above_cap() {
+    let builder = builder().with_cap(U256::from(TOKEN_TOTAL_SUPPLY));
+    assert!(matches!(builder.validate(), Err(Error::InvalidCap)));
+}
+
+#[test]
+fn should_not_validate_builder_with_overflowing_supply() {
+    let builder = builder().with_initial_holder(HOLDER, U256::MAX);
+    assert!(matches!(builder.validate(), Err(Error::Overflow)));
+}
+
+#[test]
+fn should_extend_default_entry_points_with_mint_and_burn() {
+    let default_entry_points = entry_points::default();
+    let mintable_entry_points = entry_points::mintable();
+
+    assert!(default_entry_points
+        .keys()
+        .all(|name| mintable_entry_points.has_entry_point(name)));
+    for name in &["mint", "burn"] {
+        assert!(!default_entry_points.has_entry_point(name));
+        assert!(mintable_entry_points.has_entry_point(name));
+    }
+}
@@ -0,0 +1,86 @@
+use std::convert::TryFrom;
+
+use casper_erc20::{
+    error::{
+        ERROR_FLASH_LOAN_IN_PROGRESS, ERROR_INSUFFICIENT_BALANCE, ERROR_INVALID_CONFIGURATION,
+        ERROR_INVALID_CONTEXT, MIN_RESERVED_ERROR,
+    },
+    Error,
+};
+use casper_types::ApiError;
+
+#[test]
+fn should_round_trip_library_errors_through_api_error() {
+    let errors = [
+        Error::InvalidContext,
+        Error::InsufficientBalance,
+        Error::InsufficientAllowance,
+        Error::Overflow,
+        Error::Unauthorized,
+        Error::DuplicateBridgeRequest,
+        Error::Paused,
+        Error::PermitExpired,
+        Error::InvalidSignature,
+        Error::CapExceeded,
+        Error::InvalidCap,
+        Error::Frozen,
+        Error::InvalidSnapshot,
+        Error::InvalidBlockTime,
+        Error::CallbackRejected,
+        Error::BatchLengthMismatch,
+        Error::InvalidReceiver,
+        Error::AllowanceExpired,
+        Error::InvalidConfiguration,
+        Error::FlashLoanTooLarge,
+        Error::FlashLoanInProgress,
+        Error::User(0),
+        Error::User(MIN_RESERVED_ERROR - 1),
+    ];
+
+    for error in errors.iter().copied() {
+        assert_eq!(Error::try_from(ApiError::from(error)), Ok(error));
+    }
+}
+
+#[test]
+fn should_decode_published_codes() {
+    assert_eq!(
+        Error::try_from(ERROR_INVALID_CONTEXT),
+        Ok(Error::InvalidContext)
+    );
+    assert_eq!(
+        Error::try_from(ERROR_INSUFFICIENT_BALANCE),
+        Ok(Error::InsufficientBalance)
+    );
+    assert_eq!(
+        ApiError::from(Error::InvalidConfiguration),
+        ApiError::User(ERROR_INVALID_CONFIGURATION)
+    );
+    assert_eq!(Error::try_from(42), Ok(Error::User(42)));
+}
+
+#[test]
+fn should_not_decode_unknown_reserved_codes() {
+    assert_eq!(Error::try_from(MIN_RESERVED_ERROR), Err(MIN_RESERVED_ERROR));
+    assert_eq!(
+        Error::try_from(ApiError::User(ERROR_FLASH_LOAN_IN_PROGRESS - 1)),
+        Err(ApiError::User(ERROR_FLASH_LOAN_IN_PROGRESS - 1))
+    );
+}
+
+#[test]
+fn should_not_decode_non_user_api_errors() {
+    assert_eq!(
+        Error::try_from(ApiError::MissingArgument),
+        Err(ApiError::MissingArgument)
+    );
+}
+
+#[test]
+fn should_display_errors() {
+    assert_eq!(
+        Error::InsufficientBalance.to_string(),
+        "insufficient balance"
+    );
+    assert_eq!(Error::User(42).to_string(), "user error 42");
+}
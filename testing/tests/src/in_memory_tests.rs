@@ -0,0 +1,363 @@
+use once_cell::sync::Lazy;
+
+use casper_erc20::{
+    constants::{MINTER_ROLE, PAUSER_ROLE},
+    Address, Error, Event, MemoryStorage, TokenMetadata, ERC20,
+};
+use casper_types::{account::AccountHash, bytesrepr::Bytes, PublicKey, U256};
+
+const TOKEN_NAME: &str = "CasperTest";
+const TOKEN_SYMBOL: &str = "CSPRT";
+const TOKEN_DECIMALS: u8 = 100;
+const TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+
+const RENAMED_TOKEN_NAME: &str = "CasperRenamed";
+const RENAMED_TOKEN_SYMBOL: &str = "CSPRR";
+const TOKEN_WEBSITE: &str = "https://casper.network";
+
+const TRANSFER_AMOUNT: u64 = 42;
+const ALLOWANCE_AMOUNT: u64 = 456_789;
+const ALLOWANCE_EXPIRES_AT: u64 = 10_000;
+const SNAPSHOTS_COUNT: u64 = 10;
+const CHECKPOINTS_COUNT: u64 = 10;
+
+static OWNER: Lazy<Address> = Lazy::new(|| Address::from(AccountHash::new([1; 32])));
+static ACCOUNT_1: Lazy<Address> = Lazy::new(|| Address::from(AccountHash::new([2; 32])));
+static ACCOUNT_2: Lazy<Address> = Lazy::new(|| Address::from(AccountHash::new([3; 32])));
+
+fn setup() -> ERC20<MemoryStorage> {
+    ERC20::in_memory(
+        TOKEN_NAME.to_string(),
+        TOKEN_SYMBOL.to_string(),
+        TOKEN_DECIMALS,
+        U256::from(TOKEN_TOTAL_SUPPLY),
+        *OWNER,
+    )
+    .unwrap_or_else(|_| panic!("should create token"))
+}
+
+fn call_as(erc20: &mut ERC20<MemoryStorage>, caller: Address) -> &mut ERC20<MemoryStorage> {
+    erc20.storage_mut().set_caller(caller);
+    erc20
+}
+
+#[test]
+fn should_set_up_token_in_memory() {
+    let erc20 = setup();
+
+    assert_eq!(erc20.name(), TOKEN_NAME);
+    assert_eq!(erc20.symbol(), TOKEN_SYMBOL);
+    assert_eq!(erc20.decimals(), TOKEN_DECIMALS);
+    assert_eq!(erc20.total_supply(), U256::from(TOKEN_TOTAL_SUPPLY));
+    assert_eq!(erc20.balance_of(*OWNER), U256::from(TOKEN_TOTAL_SUPPLY));
+    assert!(erc20.has_role(MINTER_ROLE, *OWNER));
+    assert!(erc20.has_role(PAUSER_ROLE, *OWNER));
+    assert_eq!(erc20.events_count(), 1);
+    assert_eq!(
+        erc20.event(0),
+        Some(Event::Mint {
+            recipient: *OWNER,
+            amount: U256::from(TOKEN_TOTAL_SUPPLY),
+        })
+    );
+}
+
+#[test]
+fn should_transfer_in_memory() {
+    let mut erc20 = setup();
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+
+    assert_eq!(erc20.balance_of(*ACCOUNT_1), amount);
+    assert_eq!(
+        erc20.balance_of(*OWNER),
+        U256::from(TOKEN_TOTAL_SUPPLY) - amount
+    );
+    assert_eq!(
+        erc20.event(1),
+        Some(Event::Transfer {
+            sender: *OWNER,
+            recipient: *ACCOUNT_1,
+            amount,
+        })
+    );
+
+    let result = call_as(&mut erc20, *ACCOUNT_1).transfer(*ACCOUNT_2, amount + 1);
+    assert!(matches!(result, Err(Error::InsufficientBalance)));
+}
+
+#[test]
+fn should_transfer_from_in_memory() {
+    let mut erc20 = setup();
+    let allowance = U256::from(ALLOWANCE_AMOUNT);
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    assert!(erc20.approve(*ACCOUNT_1, allowance).is_ok());
+    assert_eq!(erc20.allowance(*OWNER, *ACCOUNT_1), allowance);
+
+    let erc20 = call_as(&mut erc20, *ACCOUNT_1);
+    assert!(erc20.transfer_from(*OWNER, *ACCOUNT_2, amount).is_ok());
+    assert_eq!(erc20.balance_of(*ACCOUNT_2), amount);
+    assert_eq!(erc20.allowance(*OWNER, *ACCOUNT_1), allowance - amount);
+
+    let result = erc20.transfer_from(*OWNER, *ACCOUNT_2, allowance);
+    assert!(matches!(result, Err(Error::InsufficientAllowance)));
+}
+
+#[test]
+fn should_expire_allowance_in_memory() {
+    let mut erc20 = setup();
+    let allowance = U256::from(ALLOWANCE_AMOUNT);
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    assert!(erc20
+        .approve_with_expiry(*ACCOUNT_1, allowance, ALLOWANCE_EXPIRES_AT)
+        .is_ok());
+    assert!(erc20.approve(*ACCOUNT_2, allowance).is_ok());
+
+    erc20.storage_mut().set_block_time(ALLOWANCE_EXPIRES_AT);
+    assert_eq!(erc20.allowance(*OWNER, *ACCOUNT_1), allowance);
+    assert!(call_as(&mut erc20, *ACCOUNT_1)
+        .transfer_from(*OWNER, *ACCOUNT_1, amount)
+        .is_ok());
+
+    erc20.storage_mut().set_block_time(ALLOWANCE_EXPIRES_AT + 1);
+    assert_eq!(erc20.allowance(*OWNER, *ACCOUNT_1), U256::zero());
+    assert!(matches!(
+        erc20.transfer_from(*OWNER, *ACCOUNT_1, amount),
+        Err(Error::AllowanceExpired)
+    ));
+    assert!(matches!(
+        call_as(&mut erc20, *OWNER).increase_allowance(*ACCOUNT_1, amount),
+        Err(Error::AllowanceExpired)
+    ));
+    assert!(matches!(
+        erc20.approve_with_expiry(*ACCOUNT_1, allowance, ALLOWANCE_EXPIRES_AT),
+        Err(Error::AllowanceExpired)
+    ));
+
+    // Plain approvals never expire, and replace an expired allowance.
+    assert_eq!(erc20.allowance(*OWNER, *ACCOUNT_2), allowance);
+    assert!(erc20.approve(*ACCOUNT_1, allowance).is_ok());
+    assert!(call_as(&mut erc20, *ACCOUNT_1)
+        .transfer_from(*OWNER, *ACCOUNT_1, amount)
+        .is_ok());
+}
+
+#[test]
+fn should_not_permit_without_chain_name_in_memory() {
+    let mut erc20 = setup();
+
+    assert_eq!(erc20.chain_name(), "");
+    assert!(matches!(
+        erc20.permit(
+            PublicKey::System,
+            *ACCOUNT_1,
+            U256::from(ALLOWANCE_AMOUNT),
+            u64::MAX,
+            Bytes::new(),
+        ),
+        Err(Error::InvalidConfiguration)
+    ));
+    assert_eq!(erc20.allowance(*OWNER, *ACCOUNT_1), U256::zero());
+}
+
+#[test]
+fn should_enforce_cap_in_memory() {
+    let mut erc20 = setup();
+    let cap = U256::from(TOKEN_TOTAL_SUPPLY) + 1;
+
+    assert!(matches!(
+        erc20.set_cap(U256::from(TOKEN_TOTAL_SUPPLY) - 1),
+        Err(Error::InvalidCap)
+    ));
+    assert!(erc20.set_cap(cap).is_ok());
+
+    assert!(erc20.mint(*ACCOUNT_1, U256::one()).is_ok());
+    assert!(matches!(
+        erc20.mint(*ACCOUNT_1, U256::one()),
+        Err(Error::CapExceeded)
+    ));
+    assert_eq!(erc20.total_supply(), cap);
+}
+
+#[test]
+fn should_pause_in_memory() {
+    let mut erc20 = setup();
+
+    assert!(matches!(
+        call_as(&mut erc20, *ACCOUNT_1).pause(),
+        Err(Error::Unauthorized)
+    ));
+
+    assert!(call_as(&mut erc20, *OWNER).pause().is_ok());
+    assert!(erc20.is_paused());
+    assert!(matches!(
+        erc20.transfer(*ACCOUNT_1, U256::one()),
+        Err(Error::Paused)
+    ));
+
+    assert!(erc20.unpause().is_ok());
+    assert!(erc20.transfer(*ACCOUNT_1, U256::one()).is_ok());
+}
+
+#[test]
+fn should_freeze_in_memory() {
+    let mut erc20 = setup();
+
+    assert!(erc20.freeze(*ACCOUNT_1).is_ok());
+    assert!(erc20.is_frozen(*ACCOUNT_1));
+    assert!(matches!(
+        erc20.transfer(*ACCOUNT_1, U256::one()),
+        Err(Error::Frozen)
+    ));
+
+    assert!(erc20.unfreeze(*ACCOUNT_1).is_ok());
+    assert!(erc20.transfer(*ACCOUNT_1, U256::one()).is_ok());
+}
+
+#[test]
+fn should_take_snapshots_in_memory() {
+    let mut erc20 = setup();
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+    let snapshot_id = erc20
+        .snapshot()
+        .unwrap_or_else(|_| panic!("should take snapshot"));
+    assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+    assert!(erc20.burn(*ACCOUNT_1, amount).is_ok());
+
+    assert!(matches!(
+        erc20.balance_of_at(*ACCOUNT_1, snapshot_id),
+        Ok(balance) if balance == amount
+    ));
+    assert!(matches!(
+        erc20.total_supply_at(snapshot_id),
+        Ok(total_supply) if total_supply == U256::from(TOKEN_TOTAL_SUPPLY)
+    ));
+    assert!(matches!(
+        erc20.balance_of_at(*ACCOUNT_1, snapshot_id + 1),
+        Err(Error::InvalidSnapshot)
+    ));
+}
+
+#[test]
+fn should_look_up_many_snapshots_in_memory() {
+    let mut erc20 = setup();
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    let mut expected_balances = Vec::new();
+    for round in 1..=SNAPSHOTS_COUNT {
+        assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+        let balance = amount * round;
+        // Every other snapshot is taken without any balance change since the previous one.
+        for _ in 0..=(round % 2) {
+            let snapshot_id = erc20
+                .snapshot()
+                .unwrap_or_else(|_| panic!("should take snapshot"));
+            expected_balances.push((snapshot_id, balance));
+        }
+    }
+    assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+
+    for (snapshot_id, expected_balance) in expected_balances {
+        assert!(matches!(
+            erc20.balance_of_at(*ACCOUNT_1, snapshot_id),
+            Ok(balance) if balance == expected_balance
+        ));
+    }
+}
+
+#[test]
+fn should_track_past_votes_in_memory() {
+    let mut erc20 = setup();
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    erc20.storage_mut().set_block_time(1);
+    assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+
+    erc20.storage_mut().set_block_time(2);
+    assert!(call_as(&mut erc20, *ACCOUNT_1).delegate(*ACCOUNT_2).is_ok());
+    assert_eq!(erc20.get_votes(*ACCOUNT_2), amount);
+
+    erc20.storage_mut().set_block_time(3);
+    assert!(erc20.transfer(*OWNER, amount).is_ok());
+    assert_eq!(erc20.get_votes(*ACCOUNT_2), U256::zero());
+
+    assert!(matches!(
+        erc20.get_past_votes(*ACCOUNT_2, 1),
+        Ok(votes) if votes.is_zero()
+    ));
+    assert!(matches!(
+        erc20.get_past_votes(*ACCOUNT_2, 2),
+        Ok(votes) if votes == amount
+    ));
+    assert!(matches!(
+        erc20.get_past_votes(*ACCOUNT_2, 3),
+        Err(Error::InvalidBlockTime)
+    ));
+}
+
+#[test]
+fn should_look_up_many_past_votes_in_memory() {
+    let mut erc20 = setup();
+    let amount = U256::from(TRANSFER_AMOUNT);
+
+    assert!(erc20.delegate(*ACCOUNT_2).is_ok());
+    for block_time in 1..=CHECKPOINTS_COUNT {
+        erc20.storage_mut().set_block_time(block_time);
+        // Each transfer moves votes away from the delegatee, and records a checkpoint.
+        assert!(erc20.transfer(*ACCOUNT_1, amount).is_ok());
+    }
+    erc20.storage_mut().set_block_time(CHECKPOINTS_COUNT + 1);
+
+    for block_time in 0..=CHECKPOINTS_COUNT {
+        let expected_votes = U256::from(TOKEN_TOTAL_SUPPLY) - amount * block_time;
+        assert!(matches!(
+            erc20.get_past_votes(*ACCOUNT_2, block_time),
+            Ok(votes) if votes == expected_votes
+        ));
+    }
+}
+
+#[test]
+fn should_update_metadata_in_memory() {
+    let mut erc20 = setup();
+    let metadata = TokenMetadata {
+        website: Some(TOKEN_WEBSITE.to_string()),
+        origin_chain_id: Some(1),
+        ..TokenMetadata::default()
+    };
+
+    assert_eq!(erc20.metadata(), TokenMetadata::default());
+    assert!(matches!(
+        call_as(&mut erc20, *ACCOUNT_1).set_metadata(metadata.clone()),
+        Err(Error::Unauthorized)
+    ));
+    assert!(matches!(
+        erc20.rename(
+            RENAMED_TOKEN_NAME.to_string(),
+            RENAMED_TOKEN_SYMBOL.to_string()
+        ),
+        Err(Error::Unauthorized)
+    ));
+
+    let erc20 = call_as(&mut erc20, *OWNER);
+    assert!(erc20.set_metadata(metadata.clone()).is_ok());
+    assert_eq!(erc20.metadata(), metadata);
+
+    assert!(matches!(
+        erc20.rename(String::new(), RENAMED_TOKEN_SYMBOL.to_string()),
+        Err(Error::InvalidConfiguration)
+    ));
+    assert!(erc20
+        .rename(
+            RENAMED_TOKEN_NAME.to_string(),
+            RENAMED_TOKEN_SYMBOL.to_string()
+        )
+        .is_ok());
+    assert_eq!(erc20.name(), RENAMED_TOKEN_NAME);
+    assert_eq!(erc20.symbol(), RENAMED_TOKEN_SYMBOL);
+}
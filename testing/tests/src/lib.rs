@@ -1,2 +1,12 @@
 #[cfg(test)]
+mod address_tests;
+#[cfg(test)]
+mod builder_tests;
+#[cfg(test)]
+mod error_tests;
+#[cfg(test)]
+mod in_memory_tests;
+#[cfg(test)]
 mod lib_integration_tests;
+#[cfg(test)]
+mod namespaced_tests;
@@ -4,13 +4,29 @@ use casper_engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
     DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
+use casper_erc20::{
+    client,
+    error::{
+        ERROR_ALLOWANCE_EXPIRED, ERROR_BATCH_LENGTH_MISMATCH, ERROR_CALLBACK_REJECTED,
+        ERROR_CAP_EXCEEDED, ERROR_DUPLICATE_BRIDGE_REQUEST, ERROR_FLASH_LOAN_IN_PROGRESS,
+        ERROR_FLASH_LOAN_TOO_LARGE, ERROR_FROZEN, ERROR_INSUFFICIENT_ALLOWANCE,
+        ERROR_INSUFFICIENT_BALANCE, ERROR_INVALID_BLOCK_TIME, ERROR_INVALID_CAP,
+        ERROR_INVALID_RECEIVER, ERROR_INVALID_SIGNATURE, ERROR_INVALID_SNAPSHOT, ERROR_OVERFLOW,
+        ERROR_PAUSED, ERROR_PERMIT_EXPIRED, ERROR_UNAUTHORIZED,
+    },
+    Address, AddressError, Event, TokenMetadata,
+};
 use casper_execution_engine::core::{
     engine_state::{Error as CoreError, ExecuteRequest},
     execution::Error as ExecError,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, runtime_args, system::mint, ApiError, CLTyped,
-    ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey, U256,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    crypto, runtime_args,
+    system::mint,
+    ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs,
+    SecretKey, U256,
 };
 
 const EXAMPLE_ERC20_TOKEN: &str = "erc20_token.wasm";
@@ -23,6 +39,7 @@ const DECIMALS_KEY: &str = "decimals";
 const TOTAL_SUPPLY_KEY: &str = "total_supply";
 const BALANCES_KEY: &str = "balances";
 const ALLOWANCES_KEY: &str = "allowances";
+const ROLES_KEY: &str = "roles";
 
 const ARG_NAME: &str = "name";
 const ARG_SYMBOL: &str = "symbol";
@@ -30,11 +47,10 @@ const ARG_DECIMALS: &str = "decimals";
 const ARG_TOTAL_SUPPLY: &str = "total_supply";
 
 const TEST_CONTRACT_KEY: &str = "test_contract";
-
-const _ERROR_INVALID_CONTEXT: u16 = u16::MAX;
-const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
-const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
-const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const TEST_CONTRACT_PACKAGE_HASH_KEY: &str = "test_contract_package_hash";
+const TEST_CONTRACT_ACCESS_TOKEN_KEY: &str = "test_contract_access_token";
+const TEST_CONTRACT_VERSION_KEY: &str = "test_contract_version";
+const TEST_CONTRACT_NAMED_KEYS_KEY: &str = "test_contract_named_keys";
 
 const TOKEN_NAME: &str = "CasperTest";
 const TOKEN_SYMBOL: &str = "CSPRT";
@@ -78,6 +94,8 @@ const ALLOWANCE_AMOUNT_2: u64 = 87_654;
 
 const METHOD_TRANSFER_AS_STORED_CONTRACT: &str = "transfer_as_stored_contract";
 const METHOD_APPROVE_AS_STORED_CONTRACT: &str = "approve_as_stored_contract";
+const METHOD_APPROVE_WITH_EXPIRY_AS_STORED_CONTRACT: &str =
+    "approve_with_expiry_as_stored_contract";
 const METHOD_FROM_AS_STORED_CONTRACT: &str = "transfer_from_as_stored_contract";
 
 const TOKEN_OWNER_ADDRESS_1: Key = Key::Account(AccountHash::new([42; 32]));
@@ -88,6 +106,97 @@ const TOKEN_OWNER_AMOUNT_2: u64 = 2_000_000;
 const METHOD_MINT: &str = "mint";
 const METHOD_BURN: &str = "burn";
 
+const METHOD_GRANT_ROLE: &str = "grant_role";
+const METHOD_REVOKE_ROLE: &str = "revoke_role";
+const METHOD_RENOUNCE_ROLE: &str = "renounce_role";
+const ARG_ROLE: &str = "role";
+const ARG_ACCOUNT: &str = "account";
+const ADMIN_ROLE: &str = "admin";
+const MINTER_ROLE: &str = "minter";
+
+const METHOD_BRIDGE_MINT: &str = "bridge_mint";
+const METHOD_REQUEST_BRIDGE_BACK: &str = "request_bridge_back";
+const ARG_ORIGIN_CHAIN_ID: &str = "origin_chain_id";
+const ARG_TX_HASH: &str = "tx_hash";
+const ARG_LOG_INDEX: &str = "log_index";
+const ARG_TO_CHAIN_ID: &str = "to_chain_id";
+const ARG_RECEIVER: &str = "receiver";
+const ORIGIN_CHAIN_ID: u64 = 1;
+const ORIGIN_TX_HASH: &str = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+const BRIDGE_BACK_REQUESTS_COUNT_KEY: &str = "bridge_back_requests_count";
+
+const EVENTS_KEY: &str = "events";
+const EVENTS_COUNT_KEY: &str = "events_count";
+
+const METHOD_SET_METADATA: &str = "set_metadata";
+const METHOD_RENAME: &str = "rename";
+const ARG_METADATA: &str = "metadata";
+const METADATA_KEY: &str = "metadata";
+const TEST_TOKEN_DESCRIPTION: &str = "Token used to test the casper-erc20 library";
+const TEST_TOKEN_WEBSITE: &str = "https://casper.network";
+const RENAMED_TOKEN_NAME: &str = "CasperRenamed";
+const RENAMED_TOKEN_SYMBOL: &str = "CSPRR";
+
+const METHOD_PAUSE: &str = "pause";
+const METHOD_UNPAUSE: &str = "unpause";
+const PAUSED_KEY: &str = "paused";
+
+const METHOD_PERMIT: &str = "permit";
+const ARG_PUBLIC_KEY: &str = "public_key";
+const ARG_DEADLINE: &str = "deadline";
+const ARG_SIGNATURE: &str = "signature";
+const TOKEN_CHAIN_NAME: &str = "casper-test";
+const PERMIT_BLOCK_TIME: u64 = 1_000_000;
+
+const METHOD_LOWER_CAP: &str = "lower_cap";
+const ARG_CAP: &str = "cap";
+const CAP_KEY: &str = "cap";
+const TOKEN_CAP: u64 = 10_000_000_000;
+
+const METHOD_FREEZE: &str = "freeze";
+const METHOD_UNFREEZE: &str = "unfreeze";
+
+const METHOD_SNAPSHOT: &str = "snapshot";
+const METHOD_BALANCE_OF_AT: &str = "balance_of_at";
+const ARG_SNAPSHOT_ID: &str = "snapshot_id";
+const SNAPSHOT_ID_KEY: &str = "snapshot_id";
+const CHECK_BALANCE_OF_AT_ENTRYPOINT: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT: &str = "check_total_supply_at";
+
+const METHOD_DELEGATE: &str = "delegate";
+const METHOD_GET_PAST_VOTES: &str = "get_past_votes";
+const ARG_DELEGATEE: &str = "delegatee";
+const ARG_BLOCK_TIME: &str = "block_time";
+const CHECK_GET_VOTES_ENTRYPOINT: &str = "check_get_votes";
+const CHECK_GET_PAST_VOTES_ENTRYPOINT: &str = "check_get_past_votes";
+const DELEGATE_BLOCK_TIME: u64 = 1_000;
+const TRANSFER_BLOCK_TIME: u64 = 2_000;
+const CHECK_VOTES_BLOCK_TIME: u64 = 3_000;
+
+const METHOD_TRANSFER_AND_CALL: &str = "transfer_and_call";
+const METHOD_APPROVE_AND_CALL: &str = "approve_and_call";
+const ARG_DATA: &str = "data";
+const REJECT_DATA: &[u8] = b"reject";
+const STANDING_APPROVAL_DATA: &[u8] = b"standing_approval";
+const NESTED_FLASH_LOAN_DATA: &[u8] = b"nested_flash_loan";
+
+const METHOD_BATCH_TRANSFER: &str = "batch_transfer";
+const METHOD_BATCH_TRANSFER_FROM: &str = "batch_transfer_from";
+const ARG_RECIPIENTS: &str = "recipients";
+const ARG_AMOUNTS: &str = "amounts";
+
+const METHOD_APPROVE_WITH_EXPIRY: &str = "approve_with_expiry";
+const ARG_EXPIRES_AT: &str = "expires_at";
+const ALLOWANCE_EXPIRES_AT: u64 = 10_000;
+
+const METHOD_FLASH_LOAN: &str = "flash_loan";
+const METHOD_SET_FLASH_FEE: &str = "set_flash_fee";
+const METHOD_SET_FLASH_FEE_RECIPIENT: &str = "set_flash_fee_recipient";
+const ARG_BASIS_POINTS: &str = "basis_points";
+const FLASH_FEE_BASIS_POINTS: u32 = 100;
+const FLASH_LOAN_AMOUNT: u64 = 1_000_000;
+const FLASH_LOAN_FEE: u64 = 10_000;
+
 /// Converts hash addr of Account into Hash, and Hash into Account
 ///
 /// This is useful for making sure ERC20 library respects different variants of Key when storing
@@ -313,6 +422,40 @@ fn erc20_check_allowance_of(
     get_test_result(builder, erc20_test_contract_hash)
 }
 
+fn erc20_check_allowance_of_token(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    owner: Key,
+    spender: Key,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_allowance_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_OWNER => owner,
+        ARG_SPENDER => spender,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_ALLOWANCE_OF_ENTRYPOINT,
+        check_allowance_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
 fn test_erc20_transfer(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,
@@ -442,6 +585,371 @@ fn make_erc20_approve_request(
     }
 }
 
+fn make_role_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    method: &str,
+    role: &str,
+    account: Key,
+) -> ExecuteRequest {
+    let role_args = runtime_args! {
+        ARG_ROLE => role,
+        ARG_ACCOUNT => account,
+    };
+    ExecuteRequestBuilder::contract_call_by_hash(sender, *test_contract, method, role_args).build()
+}
+
+fn make_bridge_mint_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    recipient: Key,
+    amount: U256,
+    log_index: u64,
+) -> ExecuteRequest {
+    let bridge_mint_args = runtime_args! {
+        ARG_RECIPIENT => recipient,
+        ARG_AMOUNT => amount,
+        ARG_ORIGIN_CHAIN_ID => ORIGIN_CHAIN_ID,
+        ARG_TX_HASH => ORIGIN_TX_HASH,
+        ARG_LOG_INDEX => log_index,
+    };
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        METHOD_BRIDGE_MINT,
+        bridge_mint_args,
+    )
+    .build()
+}
+
+/// Builds a request to `permit` on the test contract, signed by the first test account.
+fn make_permit_request(
+    builder: &InMemoryWasmTestBuilder,
+    test_contract: &ContractHash,
+    spender: Key,
+    amount: U256,
+    nonce: u64,
+    deadline: u64,
+) -> ExecuteRequest {
+    let contract_package_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_PACKAGE_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+
+    let owner = Address::from(*ACCOUNT_1_ADDR);
+    let message = casper_erc20::permit_message(
+        contract_package_hash,
+        TOKEN_CHAIN_NAME,
+        owner,
+        Address::Account(spender.into_account().unwrap()),
+        amount,
+        nonce,
+        deadline,
+    )
+    .unwrap();
+    let signature = crypto::sign(&message, &*ACCOUNT_1_SECRET_KEY, &*ACCOUNT_1_PUBLIC_KEY);
+
+    let permit_args = runtime_args! {
+        ARG_PUBLIC_KEY => ACCOUNT_1_PUBLIC_KEY.clone(),
+        ARG_SPENDER => spender,
+        ARG_AMOUNT => amount,
+        ARG_DEADLINE => deadline,
+        ARG_SIGNATURE => Bytes::from(signature.to_bytes().unwrap()),
+    };
+    // Anyone is able to submit a permit on behalf of the owner.
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *test_contract,
+        METHOD_PERMIT,
+        permit_args,
+    )
+    .with_block_time(PERMIT_BLOCK_TIME)
+    .build()
+}
+
+fn make_lower_cap_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    cap: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        METHOD_LOWER_CAP,
+        runtime_args! {
+            ARG_CAP => cap,
+        },
+    )
+    .build()
+}
+
+fn make_freeze_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    method: &str,
+    address: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        method,
+        runtime_args! {
+            ARG_ADDRESS => address,
+        },
+    )
+    .build()
+}
+
+fn make_snapshot_request(sender: AccountHash, test_contract: &ContractHash) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        METHOD_SNAPSHOT,
+        RuntimeArgs::default(),
+    )
+    .build()
+}
+
+fn erc20_check_balance_of_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    address: Key,
+    snapshot_id: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_balance_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_ADDRESS => address,
+        ARG_SNAPSHOT_ID => snapshot_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_BALANCE_OF_AT_ENTRYPOINT,
+        check_balance_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+fn erc20_check_total_supply_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    snapshot_id: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_total_supply_at_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_SNAPSHOT_ID => snapshot_id,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT,
+        check_total_supply_at_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+fn erc20_check_votes(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    address: Key,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_votes_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_ADDRESS => address,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_GET_VOTES_ENTRYPOINT,
+        check_votes_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+fn erc20_check_past_votes(
+    builder: &mut InMemoryWasmTestBuilder,
+    erc20_contract_hash: &ContractHash,
+    address: Key,
+    block_time: u64,
+) -> U256 {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let erc20_test_contract_hash = account
+        .named_keys()
+        .get(ERC20_TEST_CALL_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have test contract hash");
+
+    let check_past_votes_args = runtime_args! {
+        ARG_TOKEN_CONTRACT => *erc20_contract_hash,
+        ARG_ADDRESS => address,
+        ARG_BLOCK_TIME => block_time,
+    };
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_contract_hash,
+        None,
+        CHECK_GET_PAST_VOTES_ENTRYPOINT,
+        check_past_votes_args,
+    )
+    .with_block_time(CHECK_VOTES_BLOCK_TIME)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, erc20_test_contract_hash)
+}
+
+fn make_delegate_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    delegatee: Key,
+    block_time: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        METHOD_DELEGATE,
+        runtime_args! {
+            ARG_DELEGATEE => delegatee,
+        },
+    )
+    .with_block_time(block_time)
+    .build()
+}
+
+fn make_and_call_request(
+    test_contract: &ContractHash,
+    method: &str,
+    address_arg: &str,
+    address: Key,
+    amount: U256,
+    data: &[u8],
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *test_contract,
+        method,
+        runtime_args! {
+            address_arg => address,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(data.to_vec()),
+        },
+    )
+    .build()
+}
+
+fn make_batch_transfer_request(
+    sender: AccountHash,
+    test_contract: &ContractHash,
+    recipients: Vec<Key>,
+    amounts: Vec<U256>,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        *test_contract,
+        METHOD_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENTS => recipients,
+            ARG_AMOUNTS => amounts,
+        },
+    )
+    .build()
+}
+
+fn make_flash_loan_request(
+    test_contract: &ContractHash,
+    receiver: Key,
+    amount: U256,
+    data: &[u8],
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        *test_contract,
+        METHOD_FLASH_LOAN,
+        runtime_args! {
+            ARG_RECEIVER => receiver,
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(data.to_vec()),
+        },
+    )
+    .build()
+}
+
+fn query_erc20_dictionary_item(
+    builder: &InMemoryWasmTestBuilder,
+    erc20_token: ContractHash,
+    dictionary_name: &str,
+    item_key: &str,
+) -> CLValue {
+    let dictionary_seed_uref = builder
+        .get_contract(erc20_token)
+        .expect("should have contract")
+        .named_keys()
+        .get(dictionary_name)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have dictionary");
+
+    builder
+        .query_dictionary_item(None, dictionary_seed_uref, item_key)
+        .expect("should have dictionary item")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+}
+
 fn test_approve_for(
     builder: &mut InMemoryWasmTestBuilder,
     test_context: &TestContext,
@@ -533,6 +1041,8 @@ fn should_not_store_balances_or_allowances_under_account_after_install() {
     let named_keys = account.named_keys();
     assert!(!named_keys.contains_key(BALANCES_KEY), "{:?}", named_keys);
     assert!(!named_keys.contains_key(ALLOWANCES_KEY), "{:?}", named_keys);
+    assert!(!named_keys.contains_key(ROLES_KEY), "{:?}", named_keys);
+    assert!(!named_keys.contains_key(EVENTS_KEY), "{:?}", named_keys);
 }
 
 #[test]
@@ -1322,3 +1832,1773 @@ fn should_verify_zero_amount_transfer_from_is_noop() {
     let spender_allowance_after = erc20_check_allowance_of(&mut builder, owner, spender);
     assert_eq!(spender_allowance_after, spender_allowance_before);
 }
+
+#[test]
+fn should_grant_role_by_admin() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let grant_request = make_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        ADMIN_ROLE,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    builder.exec(grant_request).expect_success().commit();
+
+    // A newly appointed admin is able to grant roles on its own.
+    let grant_request = make_role_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        MINTER_ROLE,
+        Key::Account(*ACCOUNT_2_ADDR),
+    );
+    builder.exec(grant_request).expect_success().commit();
+}
+
+#[test]
+fn should_not_grant_role_without_admin_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let grant_request = make_role_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        MINTER_ROLE,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    builder.exec(grant_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_grant_role_after_admin_role_is_revoked() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let grant_request = make_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        ADMIN_ROLE,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    builder.exec(grant_request).expect_success().commit();
+
+    let revoke_request = make_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_REVOKE_ROLE,
+        ADMIN_ROLE,
+        Key::Account(*ACCOUNT_1_ADDR),
+    );
+    builder.exec(revoke_request).expect_success().commit();
+
+    let grant_request = make_role_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        MINTER_ROLE,
+        Key::Account(*ACCOUNT_2_ADDR),
+    );
+    builder.exec(grant_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_grant_role_after_admin_role_is_renounced() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let renounce_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_RENOUNCE_ROLE,
+        runtime_args! {
+            ARG_ROLE => ADMIN_ROLE,
+        },
+    )
+    .build();
+    builder.exec(renounce_request).expect_success().commit();
+
+    let grant_request = make_role_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_GRANT_ROLE,
+        ADMIN_ROLE,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    );
+    builder.exec(grant_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_bridge_mint_once_per_request() {
+    let mint_amount = U256::from(TRANSFER_AMOUNT_1);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+    let balance_before = erc20_check_balance_of(&mut builder, &test_contract, recipient);
+    let total_supply_before = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let bridge_mint_request = make_bridge_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        recipient,
+        mint_amount,
+        0,
+    );
+    builder.exec(bridge_mint_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, recipient),
+        balance_before + mint_amount,
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply_before + mint_amount,
+    );
+
+    // Another event emitted by the same origin transaction is a distinct request.
+    let bridge_mint_request = make_bridge_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        recipient,
+        mint_amount,
+        1,
+    );
+    builder.exec(bridge_mint_request).expect_success().commit();
+
+    let bridge_mint_request = make_bridge_mint_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        recipient,
+        mint_amount,
+        0,
+    );
+    builder.exec(bridge_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_DUPLICATE_BRIDGE_REQUEST),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_bridge_mint_without_minter_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let bridge_mint_request = make_bridge_mint_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        Key::Account(*ACCOUNT_1_ADDR),
+        U256::one(),
+        0,
+    );
+    builder.exec(bridge_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_burn_and_record_bridge_back_request() {
+    let burn_amount = U256::from(TRANSFER_AMOUNT_2);
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+    let balance_before = erc20_check_balance_of(&mut builder, &test_contract, sender);
+    let total_supply_before = erc20_check_total_supply(&mut builder, &test_contract);
+
+    for _ in 0..2 {
+        let request_bridge_back_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+            METHOD_REQUEST_BRIDGE_BACK,
+            runtime_args! {
+                ARG_AMOUNT => burn_amount,
+                ARG_TO_CHAIN_ID => ORIGIN_CHAIN_ID,
+                ARG_RECEIVER => Bytes::from(vec![42u8; 20]),
+            },
+        )
+        .build();
+        builder
+            .exec(request_bridge_back_request)
+            .expect_success()
+            .commit();
+    }
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, sender),
+        balance_before - (burn_amount + burn_amount),
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply_before - (burn_amount + burn_amount),
+    );
+
+    let requests_count: u64 = builder.get_value(test_contract, BRIDGE_BACK_REQUESTS_COUNT_KEY);
+    assert_eq!(requests_count, 2);
+}
+
+#[test]
+fn should_record_events() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        erc20_token,
+        test_contract,
+        ..
+    } = test_context;
+
+    // Installation records the initial supply as minted.
+    let events_count: u64 = builder.get_value(erc20_token, EVENTS_COUNT_KEY);
+    assert_eq!(events_count, 1);
+
+    // Test contract mints tokens for two owners right after installation.
+    let events_count: u64 = builder.get_value(test_contract, EVENTS_COUNT_KEY);
+    assert_eq!(events_count, 3);
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    let transfer_request =
+        make_erc20_transfer_request(sender, &erc20_token, recipient, U256::one());
+    builder.exec(transfer_request).expect_success().commit();
+
+    let approve_request = make_erc20_approve_request(sender, &erc20_token, recipient, U256::one());
+    builder.exec(approve_request).expect_success().commit();
+
+    let events_count: u64 = builder.get_value(erc20_token, EVENTS_COUNT_KEY);
+    assert_eq!(events_count, 3);
+}
+
+#[test]
+fn should_not_mint_or_burn_without_minter_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    for method in &[METHOD_MINT, METHOD_BURN] {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            test_contract,
+            method,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => U256::one(),
+            },
+        )
+        .build();
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn should_not_mint_or_burn_while_paused() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let paused: bool = builder.get_value(test_contract, PAUSED_KEY);
+    assert!(!paused);
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    let paused: bool = builder.get_value(test_contract, PAUSED_KEY);
+    assert!(paused);
+
+    for method in &[METHOD_MINT, METHOD_BURN] {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+            method,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => U256::one(),
+            },
+        )
+        .build();
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PAUSED),
+            "{:?}",
+            error
+        );
+    }
+
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_UNPAUSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(unpause_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        U256::from(TOKEN_OWNER_AMOUNT_1) + U256::one(),
+    );
+}
+
+#[test]
+fn should_not_pause_without_pauser_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_PAUSE,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(pause_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_install_upgradeable_contract() {
+    let (builder, TestContext { test_contract, .. }) = setup();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let named_keys = account.named_keys();
+    assert!(
+        named_keys.contains_key(TEST_CONTRACT_ACCESS_TOKEN_KEY),
+        "{:?}",
+        named_keys
+    );
+    assert!(
+        named_keys.contains_key(TEST_CONTRACT_NAMED_KEYS_KEY),
+        "{:?}",
+        named_keys
+    );
+
+    let contract_package_hash = named_keys
+        .get(TEST_CONTRACT_PACKAGE_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+    let contract_package = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have contract package");
+    let (_version, contract_hash) = contract_package
+        .enabled_versions()
+        .iter()
+        .rev()
+        .next()
+        .expect("should have latest version");
+    assert_eq!(*contract_hash, test_contract);
+
+    let version_uref = named_keys
+        .get(TEST_CONTRACT_VERSION_KEY)
+        .and_then(|key| key.into_uref())
+        .expect("should have version uref");
+    let version: u32 = builder
+        .query(None, Key::from(version_uref), &[])
+        .expect("should have version")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be u32");
+    assert_eq!(version, 1);
+}
+
+#[test]
+fn should_approve_with_permit() {
+    let allowance_amount = U256::from(ALLOWANCE_AMOUNT_1);
+    let owner = Key::Account(*ACCOUNT_1_ADDR);
+    let spender = Key::Account(*ACCOUNT_2_ADDR);
+
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let permit_request = make_permit_request(
+        &builder,
+        &test_contract,
+        spender,
+        allowance_amount,
+        0,
+        PERMIT_BLOCK_TIME,
+    );
+    builder.exec(permit_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_allowance_of_token(&mut builder, &test_contract, owner, spender),
+        allowance_amount
+    );
+
+    // Nonce has been used up, so the same permit can't be replayed.
+    let permit_request = make_permit_request(
+        &builder,
+        &test_contract,
+        spender,
+        allowance_amount,
+        0,
+        PERMIT_BLOCK_TIME,
+    );
+    builder.exec(permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SIGNATURE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_permit_after_deadline() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let permit_request = make_permit_request(
+        &builder,
+        &test_contract,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(ALLOWANCE_AMOUNT_1),
+        0,
+        PERMIT_BLOCK_TIME - 1,
+    );
+    builder.exec(permit_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_PERMIT_EXPIRED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_mint_above_cap() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let cap: Option<U256> = builder.get_value(test_contract, CAP_KEY);
+    assert_eq!(cap, Some(U256::from(TOKEN_CAP)));
+
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::from(TOKEN_CAP) - total_supply + U256::one(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CAP_EXCEEDED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_lower_but_not_raise_cap() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let total_supply = erc20_check_total_supply(&mut builder, &test_contract);
+    let lower_cap_request =
+        make_lower_cap_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, total_supply);
+    builder.exec(lower_cap_request).expect_success().commit();
+
+    let cap: Option<U256> = builder.get_value(test_contract, CAP_KEY);
+    assert_eq!(cap, Some(total_supply));
+
+    let raise_cap_request = make_lower_cap_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        total_supply + U256::one(),
+    );
+    builder.exec(raise_cap_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_CAP),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_lower_cap_without_admin_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let lower_cap_request =
+        make_lower_cap_request(*ACCOUNT_1_ADDR, &test_contract, U256::from(TOKEN_CAP));
+    builder.exec(lower_cap_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_move_tokens_of_frozen_account() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => account_1,
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT_1),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    let freeze_request = make_freeze_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_FREEZE,
+        account_1,
+    );
+    builder.exec(freeze_request).expect_success().commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => account_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    let send_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+
+    for request in vec![mint_request, send_request] {
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FROZEN),
+            "{:?}",
+            error
+        );
+    }
+
+    let unfreeze_request = make_freeze_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_UNFREEZE,
+        account_1,
+    );
+    builder.exec(unfreeze_request).expect_success().commit();
+
+    let send_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => U256::one(),
+        },
+    )
+    .build();
+    builder.exec(send_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, account_1),
+        U256::from(TRANSFER_AMOUNT_1) - U256::one()
+    );
+}
+
+#[test]
+fn should_not_freeze_without_admin_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let freeze_request = make_freeze_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        METHOD_FREEZE,
+        TOKEN_OWNER_ADDRESS_1,
+    );
+    builder.exec(freeze_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_query_balances_and_total_supply_at_snapshot() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let total_supply_1 = erc20_check_total_supply(&mut builder, &test_contract);
+    let balance_1 = erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1);
+
+    let snapshot_request = make_snapshot_request(*DEFAULT_ACCOUNT_ADDR, &test_contract);
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let snapshot_id: u64 = builder.get_value(test_contract, SNAPSHOT_ID_KEY);
+    assert_eq!(snapshot_id, 1);
+
+    let mint_amount = U256::from(TRANSFER_AMOUNT_1);
+    for _ in 0..2 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+            METHOD_MINT,
+            runtime_args! {
+                ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => mint_amount,
+            },
+        )
+        .build();
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let snapshot_request = make_snapshot_request(*DEFAULT_ACCOUNT_ADDR, &test_contract);
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => TOKEN_OWNER_ADDRESS_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    let balance_2 = balance_1 + mint_amount + mint_amount;
+    let total_supply_2 = total_supply_1 + mint_amount + mint_amount;
+
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1, 1),
+        balance_1
+    );
+    assert_eq!(
+        erc20_check_total_supply_at(&mut builder, &test_contract, 1),
+        total_supply_1
+    );
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1, 2),
+        balance_2
+    );
+    assert_eq!(
+        erc20_check_total_supply_at(&mut builder, &test_contract, 2),
+        total_supply_2
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_1),
+        balance_2 - mint_amount
+    );
+    // Balances which did not change since the snapshot are read from the current state.
+    assert_eq!(
+        erc20_check_balance_of_at(&mut builder, &test_contract, TOKEN_OWNER_ADDRESS_2, 1),
+        U256::from(TOKEN_OWNER_AMOUNT_2)
+    );
+}
+
+#[test]
+fn should_not_query_snapshot_not_taken_yet() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    for snapshot_id in &[0u64, 1] {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+            METHOD_BALANCE_OF_AT,
+            runtime_args! {
+                ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+                ARG_SNAPSHOT_ID => *snapshot_id,
+            },
+        )
+        .build();
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_SNAPSHOT),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn should_not_snapshot_without_admin_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let snapshot_request = make_snapshot_request(*ACCOUNT_1_ADDR, &test_contract);
+    builder.exec(snapshot_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_track_delegated_votes() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let account_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let mint_amount = U256::from(TRANSFER_AMOUNT_1);
+    let transfer_amount = U256::from(TRANSFER_AMOUNT_2);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => account_1,
+            ARG_AMOUNT => mint_amount,
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    // Tokens do not count as votes until delegated.
+    assert_eq!(
+        erc20_check_votes(&mut builder, &test_contract, account_1),
+        U256::zero()
+    );
+
+    let delegate_request = make_delegate_request(
+        *ACCOUNT_1_ADDR,
+        &test_contract,
+        account_1,
+        DELEGATE_BLOCK_TIME,
+    );
+    builder.exec(delegate_request).expect_success().commit();
+    let delegate_request = make_delegate_request(
+        *ACCOUNT_2_ADDR,
+        &test_contract,
+        account_1,
+        DELEGATE_BLOCK_TIME,
+    );
+    builder.exec(delegate_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_votes(&mut builder, &test_contract, account_1),
+        mint_amount
+    );
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => account_2,
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .with_block_time(TRANSFER_BLOCK_TIME)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    // Tokens moved between two accounts delegating to the same delegatee keep their votes.
+    assert_eq!(
+        erc20_check_votes(&mut builder, &test_contract, account_1),
+        mint_amount
+    );
+
+    let delegate_request = make_delegate_request(
+        *ACCOUNT_2_ADDR,
+        &test_contract,
+        account_2,
+        TRANSFER_BLOCK_TIME,
+    );
+    builder.exec(delegate_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_votes(&mut builder, &test_contract, account_1),
+        mint_amount - transfer_amount
+    );
+    assert_eq!(
+        erc20_check_votes(&mut builder, &test_contract, account_2),
+        transfer_amount
+    );
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_BURN,
+        runtime_args! {
+            ARG_OWNER => account_2,
+            ARG_AMOUNT => transfer_amount,
+        },
+    )
+    .with_block_time(TRANSFER_BLOCK_TIME)
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_votes(&mut builder, &test_contract, account_2),
+        U256::zero()
+    );
+
+    assert_eq!(
+        erc20_check_past_votes(
+            &mut builder,
+            &test_contract,
+            account_1,
+            DELEGATE_BLOCK_TIME - 1
+        ),
+        U256::zero()
+    );
+    assert_eq!(
+        erc20_check_past_votes(&mut builder, &test_contract, account_1, DELEGATE_BLOCK_TIME),
+        mint_amount
+    );
+    assert_eq!(
+        erc20_check_past_votes(&mut builder, &test_contract, account_2, DELEGATE_BLOCK_TIME),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_get_past_votes_of_current_block() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let past_votes_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_GET_PAST_VOTES,
+        runtime_args! {
+            ARG_ADDRESS => TOKEN_OWNER_ADDRESS_1,
+            ARG_BLOCK_TIME => CHECK_VOTES_BLOCK_TIME,
+        },
+    )
+    .with_block_time(CHECK_VOTES_BLOCK_TIME)
+    .build();
+    builder.exec(past_votes_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_BLOCK_TIME),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_transfer_and_call_contract() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let receiver = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(TRANSFER_AMOUNT_1);
+    let sender_balance_before = erc20_check_balance_of(
+        &mut builder,
+        &test_contract,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+    );
+
+    let transfer_request = make_and_call_request(
+        &test_contract,
+        METHOD_TRANSFER_AND_CALL,
+        ARG_RECIPIENT,
+        receiver,
+        amount,
+        b"payment",
+    );
+    builder.exec(transfer_request).expect_success().commit();
+
+    let received: U256 = get_test_result(&mut builder, erc20_test_call);
+    assert_eq!(received, amount);
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, receiver),
+        amount
+    );
+    assert_eq!(
+        erc20_check_balance_of(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        sender_balance_before - amount
+    );
+}
+
+#[test]
+fn should_not_transfer_and_call_contract_rejecting_tokens() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let receiver = Key::Hash(erc20_test_call.value());
+
+    for (method, address_arg) in &[
+        (METHOD_TRANSFER_AND_CALL, ARG_RECIPIENT),
+        (METHOD_APPROVE_AND_CALL, ARG_SPENDER),
+    ] {
+        let request = make_and_call_request(
+            &test_contract,
+            method,
+            address_arg,
+            receiver,
+            U256::from(TRANSFER_AMOUNT_1),
+            REJECT_DATA,
+        );
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_CALLBACK_REJECTED),
+            "{:?}",
+            error
+        );
+    }
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, receiver),
+        U256::zero()
+    );
+    assert_eq!(
+        erc20_check_allowance_of_token(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            receiver
+        ),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_approve_and_call_contract() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let spender = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(ALLOWANCE_AMOUNT_1);
+
+    let approve_request = make_and_call_request(
+        &test_contract,
+        METHOD_APPROVE_AND_CALL,
+        ARG_SPENDER,
+        spender,
+        amount,
+        b"subscription",
+    );
+    builder.exec(approve_request).expect_success().commit();
+
+    let received: U256 = get_test_result(&mut builder, erc20_test_call);
+    assert_eq!(received, amount);
+
+    assert_eq!(
+        erc20_check_allowance_of_token(
+            &mut builder,
+            &test_contract,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            spender
+        ),
+        amount
+    );
+}
+
+#[test]
+fn should_batch_transfer() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipients = vec![
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+    ];
+    let amounts = vec![
+        U256::from(TRANSFER_AMOUNT_1),
+        U256::from(TRANSFER_AMOUNT_2),
+        U256::one(),
+    ];
+    let owner_balance_before = erc20_check_balance_of(&mut builder, &test_contract, owner);
+
+    let batch_transfer_request =
+        make_batch_transfer_request(*DEFAULT_ACCOUNT_ADDR, &test_contract, recipients, amounts);
+    builder
+        .exec(batch_transfer_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::from(TRANSFER_AMOUNT_1) + U256::one()
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(TRANSFER_AMOUNT_2)
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, owner),
+        owner_balance_before
+            - U256::from(TRANSFER_AMOUNT_1)
+            - U256::from(TRANSFER_AMOUNT_2)
+            - U256::one()
+    );
+}
+
+#[test]
+fn should_not_batch_transfer_with_invalid_input() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let mismatched_request = make_batch_transfer_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+        vec![U256::one()],
+    );
+    builder.exec(mismatched_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_BATCH_LENGTH_MISMATCH),
+        "{:?}",
+        error
+    );
+
+    // The first transfer alone is affordable, but the whole batch is not.
+    let insufficient_request = make_batch_transfer_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        vec![Key::Account(*ACCOUNT_1_ADDR), Key::Account(*ACCOUNT_2_ADDR)],
+        vec![U256::from(TOKEN_TOTAL_SUPPLY), U256::one()],
+    );
+    builder.exec(insufficient_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_BALANCE),
+        "{:?}",
+        error
+    );
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, Key::Account(*ACCOUNT_1_ADDR)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_batch_transfer_from() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let allowance = U256::from(ALLOWANCE_AMOUNT_1);
+    let amounts = vec![U256::from(TRANSFER_AMOUNT_2), U256::from(TRANSFER_AMOUNT_2)];
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_APPROVE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => allowance,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let batch_transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_BATCH_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENTS => vec![spender, Key::Account(*ACCOUNT_2_ADDR)],
+            ARG_AMOUNTS => amounts,
+        },
+    )
+    .build();
+    builder
+        .exec(batch_transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, spender),
+        U256::from(TRANSFER_AMOUNT_2)
+    );
+    assert_eq!(
+        erc20_check_allowance_of_token(&mut builder, &test_contract, owner, spender),
+        allowance - U256::from(TRANSFER_AMOUNT_2) - U256::from(TRANSFER_AMOUNT_2)
+    );
+
+    let batch_transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_BATCH_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENTS => vec![spender],
+            ARG_AMOUNTS => vec![allowance],
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_from_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_transfer_from_after_allowance_expired() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let amount = U256::from(TRANSFER_AMOUNT_2);
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_APPROVE_WITH_EXPIRY,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(ALLOWANCE_AMOUNT_1),
+            ARG_EXPIRES_AT => ALLOWANCE_EXPIRES_AT,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let make_transfer_from_request = |block_time| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            test_contract,
+            METHOD_BATCH_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_RECIPIENTS => vec![spender],
+                ARG_AMOUNTS => vec![amount],
+            },
+        )
+        .with_block_time(block_time)
+        .build()
+    };
+
+    builder
+        .exec(make_transfer_from_request(ALLOWANCE_EXPIRES_AT))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, spender),
+        amount
+    );
+
+    builder
+        .exec(make_transfer_from_request(ALLOWANCE_EXPIRES_AT + 1))
+        .commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ALLOWANCE_EXPIRED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_flash_loan_to_contract() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let receiver = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(FLASH_LOAN_AMOUNT);
+    let total_supply_before = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let flash_loan_request = make_flash_loan_request(&test_contract, receiver, amount, b"loan");
+    builder.exec(flash_loan_request).expect_success().commit();
+
+    let received: U256 = get_test_result(&mut builder, erc20_test_call);
+    assert_eq!(received, amount);
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, receiver),
+        U256::zero()
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply_before
+    );
+}
+
+#[test]
+fn should_charge_flash_loan_fee() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let receiver = Key::Hash(erc20_test_call.value());
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let fee = U256::from(FLASH_LOAN_FEE);
+
+    let set_flash_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_FLASH_FEE,
+        runtime_args! {
+            ARG_BASIS_POINTS => FLASH_FEE_BASIS_POINTS,
+        },
+    )
+    .build();
+    let set_flash_fee_recipient_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_FLASH_FEE_RECIPIENT,
+        runtime_args! {
+            ARG_RECIPIENT => Some(fee_recipient),
+        },
+    )
+    .build();
+    // The receiver has to pay the fee out of its own pocket.
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_MINT,
+        runtime_args! {
+            ARG_OWNER => receiver,
+            ARG_AMOUNT => fee,
+        },
+    )
+    .build();
+    builder
+        .exec(set_flash_fee_request)
+        .expect_success()
+        .commit();
+    builder
+        .exec(set_flash_fee_recipient_request)
+        .expect_success()
+        .commit();
+    builder.exec(mint_request).expect_success().commit();
+
+    let total_supply_before = erc20_check_total_supply(&mut builder, &test_contract);
+
+    let flash_loan_request = make_flash_loan_request(
+        &test_contract,
+        receiver,
+        U256::from(FLASH_LOAN_AMOUNT),
+        b"loan",
+    );
+    builder.exec(flash_loan_request).expect_success().commit();
+
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, receiver),
+        U256::zero()
+    );
+    assert_eq!(
+        erc20_check_balance_of(&mut builder, &test_contract, fee_recipient),
+        fee
+    );
+    assert_eq!(
+        erc20_check_total_supply(&mut builder, &test_contract),
+        total_supply_before
+    );
+}
+
+#[test]
+fn should_not_flash_loan_with_invalid_input() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let receiver = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(FLASH_LOAN_AMOUNT);
+
+    let requests = vec![
+        (
+            make_flash_loan_request(&test_contract, receiver, amount, REJECT_DATA),
+            ERROR_CALLBACK_REJECTED,
+        ),
+        (
+            make_flash_loan_request(
+                &test_contract,
+                Key::Account(*ACCOUNT_1_ADDR),
+                amount,
+                b"loan",
+            ),
+            ERROR_INVALID_RECEIVER,
+        ),
+        (
+            make_flash_loan_request(&test_contract, receiver, U256::from(TOKEN_CAP), b"loan"),
+            ERROR_FLASH_LOAN_TOO_LARGE,
+        ),
+        (
+            make_flash_loan_request(&test_contract, receiver, amount, NESTED_FLASH_LOAN_DATA),
+            ERROR_FLASH_LOAN_IN_PROGRESS,
+        ),
+    ];
+
+    for (request, expected_error) in requests {
+        builder.exec(request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn should_not_flash_loan_while_fee_recipient_is_frozen() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let receiver = Key::Hash(erc20_test_call.value());
+    let fee_recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    let set_flash_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_FLASH_FEE,
+        runtime_args! {
+            ARG_BASIS_POINTS => FLASH_FEE_BASIS_POINTS,
+        },
+    )
+    .build();
+    let set_flash_fee_recipient_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_SET_FLASH_FEE_RECIPIENT,
+        runtime_args! {
+            ARG_RECIPIENT => Some(fee_recipient),
+        },
+    )
+    .build();
+    let freeze_request = make_freeze_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_contract,
+        METHOD_FREEZE,
+        fee_recipient,
+    );
+    builder
+        .exec(set_flash_fee_request)
+        .expect_success()
+        .commit();
+    builder
+        .exec(set_flash_fee_recipient_request)
+        .expect_success()
+        .commit();
+    builder.exec(freeze_request).expect_success().commit();
+
+    let flash_loan_request = make_flash_loan_request(
+        &test_contract,
+        receiver,
+        U256::from(FLASH_LOAN_AMOUNT),
+        b"loan",
+    );
+    builder.exec(flash_loan_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FROZEN),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_flash_loan_with_expired_allowance() {
+    let (
+        mut builder,
+        TestContext {
+            test_contract,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let contract_package_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_PACKAGE_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+    let receiver = Key::Hash(erc20_test_call.value());
+    let amount = U256::from(FLASH_LOAN_AMOUNT);
+
+    // The receiver approves the repayment of both loans beforehand, until the allowance expires.
+    let approve_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        erc20_test_call,
+        None,
+        METHOD_APPROVE_WITH_EXPIRY_AS_STORED_CONTRACT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => test_contract,
+            ARG_SPENDER => Key::Hash(contract_package_hash.value()),
+            ARG_AMOUNT => amount * 2,
+            ARG_EXPIRES_AT => ALLOWANCE_EXPIRES_AT,
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let make_flash_loan_request = |block_time| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+            METHOD_FLASH_LOAN,
+            runtime_args! {
+                ARG_RECEIVER => receiver,
+                ARG_AMOUNT => amount,
+                ARG_DATA => Bytes::from(STANDING_APPROVAL_DATA.to_vec()),
+            },
+        )
+        .with_block_time(block_time)
+        .build()
+    };
+
+    builder
+        .exec(make_flash_loan_request(ALLOWANCE_EXPIRES_AT))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(make_flash_loan_request(ALLOWANCE_EXPIRES_AT + 1))
+        .commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ALLOWANCE_EXPIRED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_set_flash_fee_without_admin_role() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let set_flash_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_contract,
+        METHOD_SET_FLASH_FEE,
+        runtime_args! {
+            ARG_BASIS_POINTS => FLASH_FEE_BASIS_POINTS,
+        },
+    )
+    .build();
+    builder.exec(set_flash_fee_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_derive_client_keys_matching_contract() {
+    let (
+        mut builder,
+        TestContext {
+            erc20_token,
+            erc20_test_call,
+            ..
+        },
+    ) = setup();
+
+    let owner = Address::from(*DEFAULT_ACCOUNT_ADDR);
+    let account_spender = Address::from(*ACCOUNT_1_ADDR);
+    let contract_spender = Address::from(erc20_test_call);
+
+    let approve_account_request = make_erc20_approve_request(
+        Key::from(owner),
+        &erc20_token,
+        Key::from(account_spender),
+        U256::from(ALLOWANCE_AMOUNT_1),
+    );
+    let approve_contract_request = make_erc20_approve_request(
+        Key::from(owner),
+        &erc20_token,
+        Key::from(contract_spender),
+        U256::from(ALLOWANCE_AMOUNT_2),
+    );
+    builder
+        .exec(approve_account_request)
+        .expect_success()
+        .commit();
+    builder
+        .exec(approve_contract_request)
+        .expect_success()
+        .commit();
+
+    let name: String = builder.get_value(erc20_token, client::NAME_KEY_NAME);
+    assert_eq!(name, TOKEN_NAME);
+    let decimals: u8 = builder.get_value(erc20_token, client::DECIMALS_KEY_NAME);
+    assert_eq!(decimals, TOKEN_DECIMALS);
+
+    let balance = query_erc20_dictionary_item(
+        &builder,
+        erc20_token,
+        client::BALANCES_KEY_NAME,
+        &client::balance_key(owner),
+    );
+    assert!(matches!(
+        client::decode_amount(balance),
+        Ok(balance) if balance == U256::from(TOKEN_TOTAL_SUPPLY)
+    ));
+
+    let account_allowance = query_erc20_dictionary_item(
+        &builder,
+        erc20_token,
+        client::ALLOWANCES_KEY_NAME,
+        &client::allowance_key(owner, account_spender),
+    );
+    assert!(matches!(
+        client::decode_amount(account_allowance),
+        Ok(allowance) if allowance == U256::from(ALLOWANCE_AMOUNT_1)
+    ));
+
+    let contract_allowance = query_erc20_dictionary_item(
+        &builder,
+        erc20_token,
+        client::ALLOWANCES_KEY_NAME,
+        &client::allowance_key(owner, contract_spender),
+    );
+    assert!(matches!(
+        client::decode_amount(contract_allowance),
+        Ok(allowance) if allowance == U256::from(ALLOWANCE_AMOUNT_2)
+    ));
+
+    let mint_event = query_erc20_dictionary_item(
+        &builder,
+        erc20_token,
+        client::EVENTS_KEY_NAME,
+        &client::event_key(0),
+    );
+    assert_eq!(
+        client::decode_event(mint_event).ok(),
+        Some(Event::Mint {
+            recipient: owner,
+            amount: U256::from(TOKEN_TOTAL_SUPPLY),
+        })
+    );
+}
+
+#[test]
+fn should_update_metadata_and_rename_as_admin() {
+    let (mut builder, TestContext { test_contract, .. }) = setup();
+
+    let metadata: TokenMetadata = builder.get_value(test_contract, METADATA_KEY);
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some(TEST_TOKEN_DESCRIPTION)
+    );
+
+    let metadata = TokenMetadata {
+        website: Some(TEST_TOKEN_WEBSITE.to_string()),
+        ..metadata
+    };
+    let set_metadata_request = |sender| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            test_contract,
+            METHOD_SET_METADATA,
+            runtime_args! {
+                ARG_METADATA => metadata.clone(),
+            },
+        )
+        .build()
+    };
+    let rename_request = |sender| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            test_contract,
+            METHOD_RENAME,
+            runtime_args! {
+                ARG_NAME => RENAMED_TOKEN_NAME,
+                ARG_SYMBOL => RENAMED_TOKEN_SYMBOL,
+            },
+        )
+        .build()
+    };
+
+    for request in vec![
+        set_metadata_request(*ACCOUNT_1_ADDR),
+        rename_request(*ACCOUNT_1_ADDR),
+    ] {
+        builder.exec(request).commit();
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNAUTHORIZED),
+            "{:?}",
+            error
+        );
+    }
+
+    builder
+        .exec(set_metadata_request(*DEFAULT_ACCOUNT_ADDR))
+        .expect_success()
+        .commit();
+    builder
+        .exec(rename_request(*DEFAULT_ACCOUNT_ADDR))
+        .expect_success()
+        .commit();
+
+    let stored_metadata: TokenMetadata = builder.get_value(test_contract, METADATA_KEY);
+    assert_eq!(stored_metadata, metadata);
+    let name: String = builder.get_value(test_contract, NAME_KEY);
+    assert_eq!(name, RENAMED_TOKEN_NAME);
+    let symbol: String = builder.get_value(test_contract, SYMBOL_KEY);
+    assert_eq!(symbol, RENAMED_TOKEN_SYMBOL);
+}
+
+#[test]
+fn should_derive_address_from_contract_hash_of_known_package() {
+    let (
+        builder,
+        TestContext {
+            erc20_token,
+            test_contract,
+            ..
+        },
+    ) = setup();
+
+    let contract_package_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_PACKAGE_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+    let contract_package = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have contract package");
+
+    assert!(matches!(
+        Address::from_contract_hash(test_contract, contract_package_hash, &contract_package),
+        Ok(address) if address == Address::from(contract_package_hash)
+    ));
+    assert!(matches!(
+        Address::from_contract_hash(erc20_token, contract_package_hash, &contract_package),
+        Err(AddressError::UnknownContract)
+    ));
+}
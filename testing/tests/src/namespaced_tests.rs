@@ -0,0 +1,327 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_erc20::{
+    client,
+    error::{ERROR_INVALID_CONFIGURATION, ERROR_UNAUTHORIZED},
+    Address,
+};
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, contracts::NamedKeys, runtime_args, system::mint, ApiError, ContractHash,
+    Key, RuntimeArgs, U256,
+};
+
+const CONTRACT_ERC20_NAMESPACED_TEST: &str = "erc20_namespaced_test.wasm";
+const TEST_CONTRACT_KEY: &str = "namespaced_test_contract";
+const TEST_CONTRACT_NAMED_KEYS_KEY: &str = "namespaced_test_contract_named_keys";
+
+const FIRST_TOKEN_ID: &str = "first";
+const FIRST_TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000;
+const SECOND_TOKEN_ID: &str = "second";
+const SECOND_TOKEN_NAME: &str = "CasperSecond";
+const SECOND_TOKEN_SYMBOL: &str = "CSPRS";
+const SECOND_TOKEN_DECIMALS: u8 = 6;
+const SECOND_TOKEN_TOTAL_SUPPLY: u64 = 5_000;
+
+const METHOD_CREATE_TOKEN: &str = "create_token";
+const METHOD_TRANSFER: &str = "transfer";
+const ARG_TOKEN_ID: &str = "token_id";
+const ARG_NAME: &str = "name";
+const ARG_SYMBOL: &str = "symbol";
+const ARG_DECIMALS: &str = "decimals";
+const ARG_TOTAL_SUPPLY: &str = "total_supply";
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_AMOUNT: &str = "amount";
+
+const TRANSFER_AMOUNT: u64 = 1_234;
+
+static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([1; 32]));
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    let id: Option<u64> = None;
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => *ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => MINIMUM_ACCOUNT_CREATION_BALANCE,
+            mint::ARG_ID => id,
+        },
+    )
+    .build();
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ERC20_NAMESPACED_TEST,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+    builder.exec(install_request).expect_success().commit();
+
+    let test_contract = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_KEY)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    (builder, test_contract)
+}
+
+fn make_create_token_request(sender: AccountHash, test_contract: ContractHash) -> ExecuteRequest {
+    make_create_token_request_with_id(sender, test_contract, SECOND_TOKEN_ID)
+}
+
+fn make_create_token_request_with_id(
+    sender: AccountHash,
+    test_contract: ContractHash,
+    token_id: &str,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        test_contract,
+        METHOD_CREATE_TOKEN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_NAME => SECOND_TOKEN_NAME,
+            ARG_SYMBOL => SECOND_TOKEN_SYMBOL,
+            ARG_DECIMALS => SECOND_TOKEN_DECIMALS,
+            ARG_TOTAL_SUPPLY => U256::from(SECOND_TOKEN_TOTAL_SUPPLY),
+        },
+    )
+    .build()
+}
+
+fn balance_of(
+    builder: &InMemoryWasmTestBuilder,
+    test_contract: ContractHash,
+    token_id: &str,
+    owner: Address,
+) -> U256 {
+    let dictionary_name = client::namespaced_key_name(token_id, client::BALANCES_KEY_NAME);
+    let dictionary_seed_uref = builder
+        .get_contract(test_contract)
+        .expect("should have contract")
+        .named_keys()
+        .get(&dictionary_name)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have dictionary");
+
+    match builder.query_dictionary_item(None, dictionary_seed_uref, &client::balance_key(owner)) {
+        Ok(stored_value) => {
+            let cl_value = stored_value
+                .as_cl_value()
+                .cloned()
+                .expect("should be a CLValue");
+            client::decode_amount(cl_value).expect("should decode balance")
+        }
+        Err(_) => U256::zero(),
+    }
+}
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected_error: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_install_first_token_under_namespace() {
+    let (builder, test_contract) = setup();
+
+    let token_ids: Vec<String> = builder.get_value(test_contract, client::TOKEN_IDS_KEY_NAME);
+    assert_eq!(token_ids, vec![FIRST_TOKEN_ID.to_string()]);
+
+    let total_supply: U256 = builder.get_value(
+        test_contract,
+        &client::namespaced_key_name(FIRST_TOKEN_ID, client::TOTAL_SUPPLY_KEY_NAME),
+    );
+    assert_eq!(total_supply, U256::from(FIRST_TOKEN_TOTAL_SUPPLY));
+    assert_eq!(
+        balance_of(
+            &builder,
+            test_contract,
+            FIRST_TOKEN_ID,
+            Address::from(*DEFAULT_ACCOUNT_ADDR)
+        ),
+        U256::from(FIRST_TOKEN_TOTAL_SUPPLY)
+    );
+
+    let contract = builder
+        .get_contract(test_contract)
+        .expect("should have contract");
+    assert!(!contract
+        .named_keys()
+        .contains_key(client::BALANCES_KEY_NAME));
+}
+
+#[test]
+fn should_create_and_transfer_tokens_independently() {
+    let (mut builder, test_contract) = setup();
+
+    builder
+        .exec(make_create_token_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+        ))
+        .expect_success()
+        .commit();
+
+    let token_ids: Vec<String> = builder.get_value(test_contract, client::TOKEN_IDS_KEY_NAME);
+    assert_eq!(
+        token_ids,
+        vec![FIRST_TOKEN_ID.to_string(), SECOND_TOKEN_ID.to_string()]
+    );
+    let name: String = builder.get_value(
+        test_contract,
+        &client::namespaced_key_name(SECOND_TOKEN_ID, client::NAME_KEY_NAME),
+    );
+    assert_eq!(name, SECOND_TOKEN_NAME);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_contract,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => SECOND_TOKEN_ID,
+            ARG_RECIPIENT => Address::from(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(TRANSFER_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let owner = Address::from(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Address::from(*ACCOUNT_1_ADDR);
+    assert_eq!(
+        balance_of(&builder, test_contract, SECOND_TOKEN_ID, owner),
+        U256::from(SECOND_TOKEN_TOTAL_SUPPLY - TRANSFER_AMOUNT)
+    );
+    assert_eq!(
+        balance_of(&builder, test_contract, SECOND_TOKEN_ID, recipient),
+        U256::from(TRANSFER_AMOUNT)
+    );
+    assert_eq!(
+        balance_of(&builder, test_contract, FIRST_TOKEN_ID, owner),
+        U256::from(FIRST_TOKEN_TOTAL_SUPPLY)
+    );
+    assert_eq!(
+        balance_of(&builder, test_contract, FIRST_TOKEN_ID, recipient),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_create_token_twice() {
+    let (mut builder, test_contract) = setup();
+
+    builder
+        .exec(make_create_token_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+        ))
+        .expect_success()
+        .commit();
+    builder
+        .exec(make_create_token_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+        ))
+        .commit();
+
+    assert_user_error(&builder, ERROR_INVALID_CONFIGURATION);
+}
+
+#[test]
+fn should_not_create_token_overlapping_hosted_token() {
+    let (mut builder, test_contract) = setup();
+
+    // The `name` key of this token would be the `chain_name` key of the first token.
+    let overlapping_token_id = format!("{}_chain", FIRST_TOKEN_ID);
+    let overlapping_key_name =
+        client::namespaced_key_name(&overlapping_token_id, client::NAME_KEY_NAME);
+    let chain_name: String = builder.get_value(test_contract, &overlapping_key_name);
+
+    builder
+        .exec(make_create_token_request_with_id(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+            &overlapping_token_id,
+        ))
+        .commit();
+
+    assert_user_error(&builder, ERROR_INVALID_CONFIGURATION);
+    let token_ids: Vec<String> = builder.get_value(test_contract, client::TOKEN_IDS_KEY_NAME);
+    assert_eq!(token_ids, vec![FIRST_TOKEN_ID.to_string()]);
+    let unchanged_chain_name: String = builder.get_value(test_contract, &overlapping_key_name);
+    assert_eq!(unchanged_chain_name, chain_name);
+}
+
+#[test]
+fn should_not_create_token_without_admin_role() {
+    let (mut builder, test_contract) = setup();
+
+    builder
+        .exec(make_create_token_request(*ACCOUNT_1_ADDR, test_contract))
+        .commit();
+
+    assert_user_error(&builder, ERROR_UNAUTHORIZED);
+}
+
+#[test]
+fn should_hand_over_created_token_to_new_versions() {
+    let (mut builder, test_contract) = setup();
+
+    builder
+        .exec(make_create_token_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            test_contract,
+        ))
+        .expect_success()
+        .commit();
+
+    let named_keys_uref = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_CONTRACT_NAMED_KEYS_KEY)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have named keys of new versions");
+    let shared_named_keys: NamedKeys = builder
+        .query(None, Key::URef(named_keys_uref), &[])
+        .expect("should query named keys")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should decode named keys");
+    let contract_named_keys = builder
+        .get_contract(test_contract)
+        .expect("should have contract")
+        .named_keys()
+        .clone();
+
+    // New versions get the named keys of the created token along with the installed ones.
+    assert!(shared_named_keys.contains_key(&client::namespaced_key_name(
+        SECOND_TOKEN_ID,
+        client::BALANCES_KEY_NAME
+    )));
+    assert_eq!(shared_named_keys, contract_named_keys);
+}